@@ -23,7 +23,30 @@
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+use crate::{DataKey, EventVerbosity};
+use soroban_sdk::{
+    contracttype, events::Topics, symbol_short, Address, Env, IntoVal, String, Symbol, Val,
+};
+
+/// Event verbosity chosen with `set_event_verbosity`, `Full` by default.
+pub fn event_verbosity(env: &Env) -> EventVerbosity {
+    env.storage()
+        .instance()
+        .get(&DataKey::EventVerbosity)
+        .unwrap_or(EventVerbosity::Full)
+}
+
+/// Publishes a domain event unless verbosity is `Minimal`, which keeps only
+/// `BountyClosed`.
+fn publish_domain<T, D>(env: &Env, topics: T, data: D)
+where
+    T: Topics,
+    D: IntoVal<Env, Val>,
+{
+    if event_verbosity(env) != EventVerbosity::Minimal {
+        env.events().publish(topics, data);
+    }
+}
 
 // ============================================================================
 // Contract Initialization Event
@@ -75,7 +98,7 @@ pub struct BountyEscrowInitialized {
 /// Data: Complete `BountyEscrowInitialized` struct
 pub fn emit_bounty_initialized(env: &Env, event: BountyEscrowInitialized) {
     let topics = (symbol_short!("init"),);
-    env.events().publish(topics, event.clone());
+    publish_domain(env, topics, event.clone());
 }
 
 // ============================================================================
@@ -139,7 +162,7 @@ pub struct FundsLocked {
 /// The bounty_id is included in topics for efficient filtering
 pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
     let topics = (symbol_short!("f_lock"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+    publish_domain(env, topics, event.clone());
 }
 
 // ============================================================================
@@ -194,6 +217,7 @@ pub struct FundsReleased {
     pub recipient: Address,
     pub timestamp: u64,
     pub remaining_amount: i128,
+    pub memo: Option<String>,
 }
 
 /// Emits a FundsReleased event.
@@ -207,7 +231,7 @@ pub struct FundsReleased {
 /// Data: Complete `FundsReleased` struct
 pub fn emit_funds_released(env: &Env, event: FundsReleased) {
     let topics = (symbol_short!("f_rel"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+    publish_domain(env, topics, event.clone());
 }
 
 // ============================================================================
@@ -272,6 +296,7 @@ pub struct FundsRefunded {
     pub timestamp: u64,
     pub refund_mode: crate::RefundMode,
     pub remaining_amount: i128,
+    pub memo: Option<String>,
 }
 
 /// Emits a FundsRefunded event.
@@ -285,7 +310,26 @@ pub struct FundsRefunded {
 /// Data: Complete `FundsRefunded` struct
 pub fn emit_funds_refunded(env: &Env, event: FundsRefunded) {
     let topics = (symbol_short!("f_ref"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when the designated contributor declines a bounty and its
+/// remaining funds go back to the depositor.
+///
+/// # Event Topic
+/// `(symbol_short!("declined"), bounty_id)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountyDeclined {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub refunded_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_declined(env: &Env, event: BountyDeclined) {
+    let topics = (symbol_short!("declined"), event.bounty_id);
+    publish_domain(env, topics, event.clone());
 }
 
 #[contracttype]
@@ -293,6 +337,7 @@ pub fn emit_funds_refunded(env: &Env, event: FundsRefunded) {
 pub enum FeeOperationType {
     Lock,
     Release,
+    Refund,
 }
 
 #[contracttype]
@@ -307,7 +352,7 @@ pub struct FeeCollected {
 
 pub fn emit_fee_collected(env: &Env, event: FeeCollected) {
     let topics = (symbol_short!("fee"),);
-    env.events().publish(topics, event.clone());
+    publish_domain(env, topics, event.clone());
 }
 
 #[contracttype]
@@ -320,7 +365,7 @@ pub struct BatchFundsLocked {
 
 pub fn emit_batch_funds_locked(env: &Env, event: BatchFundsLocked) {
     let topics = (symbol_short!("b_lock"),);
-    env.events().publish(topics, event.clone());
+    publish_domain(env, topics, event.clone());
 }
 
 #[contracttype]
@@ -328,6 +373,7 @@ pub fn emit_batch_funds_locked(env: &Env, event: BatchFundsLocked) {
 pub struct FeeConfigUpdated {
     pub lock_fee_rate: i128,
     pub release_fee_rate: i128,
+    pub refund_fee_rate: i128,
     pub fee_recipient: Address,
     pub fee_enabled: bool,
     pub timestamp: u64,
@@ -335,7 +381,95 @@ pub struct FeeConfigUpdated {
 
 pub fn emit_fee_config_updated(env: &Env, event: FeeConfigUpdated) {
     let topics = (symbol_short!("fee_cfg"),);
-    env.events().publish(topics, event.clone());
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when an admin config change is queued behind the time-lock.
+///
+/// # Event Topic
+/// `(symbol_short!("act_prop"), action_id)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminActionProposed {
+    pub action_id: u64,
+    pub action_type: crate::AdminActionType,
+    pub proposed_by: Address,
+    pub execute_after: u64,
+}
+
+pub fn emit_admin_action_proposed(env: &Env, event: AdminActionProposed) {
+    let topics = (symbol_short!("act_prop"), event.action_id);
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when a queued admin action is executed.
+///
+/// # Event Topic
+/// `(symbol_short!("act_exec"), action_id)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminActionExecuted {
+    pub action_id: u64,
+    pub action_type: crate::AdminActionType,
+    pub executed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_action_executed(env: &Env, event: AdminActionExecuted) {
+    let topics = (symbol_short!("act_exec"), event.action_id);
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when a queued admin action is cancelled.
+///
+/// # Event Topic
+/// `(symbol_short!("act_canc"), action_id)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminActionCancelled {
+    pub action_id: u64,
+    pub cancelled_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_action_cancelled(env: &Env, event: AdminActionCancelled) {
+    let topics = (symbol_short!("act_canc"), event.action_id);
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when the contract admin changes.
+///
+/// # Event Topic
+/// `(symbol_short!("admin_upd"),)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminUpdated {
+    pub old_admin: Address,
+    pub new_admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_updated(env: &Env, event: AdminUpdated) {
+    let topics = (symbol_short!("admin_upd"),);
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when an admin handover is proposed and waits for the new
+/// admin to accept it.
+///
+/// # Event Topic
+/// `(symbol_short!("adm_prop"),)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminProposed {
+    pub current_admin: Address,
+    pub pending_admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_proposed(env: &Env, event: AdminProposed) {
+    let topics = (symbol_short!("adm_prop"),);
+    publish_domain(env, topics, event.clone());
 }
 
 #[contracttype]
@@ -348,8 +482,39 @@ pub struct BatchFundsReleased {
 
 pub fn emit_batch_funds_released(env: &Env, event: BatchFundsReleased) {
     let topics = (symbol_short!("b_rel"),);
-    env.events().publish(topics, event.clone());
+    publish_domain(env, topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BatchFundsRefunded {
+    pub count: u32,
+    pub total_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_batch_funds_refunded(env: &Env, event: BatchFundsRefunded) {
+    let topics = (symbol_short!("b_ref"),);
+    publish_domain(env, topics, event.clone());
+}
+/// Event emitted when the admin changes the batch size limit.
+///
+/// # Event Topic
+/// `(symbol_short!("batch_cfg"),)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct MaxBatchSizeUpdated {
+    pub old_size: u32,
+    pub new_size: u32,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_max_batch_size_updated(env: &Env, event: MaxBatchSizeUpdated) {
+    let topics = (symbol_short!("batch_cfg"),);
+    publish_domain(env, topics, event.clone());
 }
+
 // ============================================================================
 // Contract Pause Events
 // ============================================================================
@@ -364,7 +529,7 @@ pub struct ContractPaused {
 
 pub fn emit_contract_paused(env: &Env, event: ContractPaused) {
     let topics = (symbol_short!("pause"),);
-    env.events().publish(topics, event.clone());
+    publish_domain(env, topics, event.clone());
 }
 
 /// Event emitted when the contract is unpaused.
@@ -377,7 +542,22 @@ pub struct ContractUnpaused {
 
 pub fn emit_contract_unpaused(env: &Env, event: ContractUnpaused) {
     let topics = (symbol_short!("unpause"),);
-    env.events().publish(topics, event.clone());
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when individual operation scopes are paused or resumed.
+/// `paused_scopes` is a bitmask with bit `PauseScope as u32` set per paused scope.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PauseScopesUpdated {
+    pub paused_scopes: u32,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_pause_scopes_updated(env: &Env, event: PauseScopesUpdated) {
+    let topics = (symbol_short!("pause_ops"),);
+    publish_domain(env, topics, event.clone());
 }
 
 /// Event emitted when emergency withdrawal occurs.
@@ -392,5 +572,205 @@ pub struct EmergencyWithdrawal {
 
 pub fn emit_emergency_withdrawal(env: &Env, event: EmergencyWithdrawal) {
     let topics = (symbol_short!("ewith"),);
+    publish_domain(env, topics, event.clone());
+}
+
+/// Emitted once per escrow drained by `emergency_withdraw_bounties`, with the
+/// bounty id as a second topic so indexers can tell which escrows were emptied.
+pub fn emit_escrow_emergency_withdrawal(env: &Env, bounty_id: u64, event: EmergencyWithdrawal) {
+    let topics = (symbol_short!("ewith"), bounty_id);
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when the admin force-closes an abandoned bounty, sending
+/// what was left in it to a treasury.
+///
+/// # Event Topic
+/// `(symbol_short!("f_close"), bounty_id)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ForceClosed {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub treasury: Address,
+    pub closed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_force_closed(env: &Env, event: ForceClosed) {
+    let topics = (symbol_short!("f_close"), event.bounty_id);
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when a new force-close delay takes effect.
+///
+/// # Event Topic
+/// `(symbol_short!("fc_delay"),)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ForceCloseDelayUpdated {
+    pub delay: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_force_close_delay_updated(env: &Env, event: ForceCloseDelayUpdated) {
+    let topics = (symbol_short!("fc_delay"),);
+    publish_domain(env, topics, event.clone());
+}
+
+// ============================================================================
+// Dispute Events
+// ============================================================================
+
+/// Event emitted when a depositor or contributor disputes a bounty.
+///
+/// # Event Topic
+/// `(symbol_short!("dispute"), bounty_id)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DisputeRaised {
+    pub bounty_id: u64,
+    pub raised_by: Address,
+    pub reason: String,
+    pub timestamp: u64,
+}
+
+pub fn emit_dispute_raised(env: &Env, event: DisputeRaised) {
+    let topics = (symbol_short!("dispute"), event.bounty_id);
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when the admin resolves a dispute, unfreezing the bounty.
+///
+/// # Event Topic
+/// `(symbol_short!("disp_res"), bounty_id)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DisputeResolved {
+    pub bounty_id: u64,
+    pub resolved_by: Address,
+    pub outcome: String,
+    pub timestamp: u64,
+}
+
+pub fn emit_dispute_resolved(env: &Env, event: DisputeResolved) {
+    let topics = (symbol_short!("disp_res"), event.bounty_id);
+    publish_domain(env, topics, event.clone());
+}
+
+// ============================================================================
+// Release Schedule Events
+// ============================================================================
+
+/// Event emitted when a release schedule is created for a bounty.
+///
+/// # Event Topic
+/// `(symbol_short!("sch_crt"), bounty_id)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ScheduleCreated {
+    pub bounty_id: u64,
+    pub schedule_id: u64,
+    pub amount: i128,
+    pub release_timestamp: u64,
+    pub recipient: Address,
+    pub created_by: Address,
+}
+
+pub fn emit_schedule_created(env: &Env, event: ScheduleCreated) {
+    let topics = (symbol_short!("sch_crt"), event.bounty_id);
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when a release schedule is executed.
+///
+/// # Event Topic
+/// `(symbol_short!("sch_rel"), bounty_id)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ScheduleReleased {
+    pub bounty_id: u64,
+    pub schedule_id: u64,
+    pub amount: i128,
+    pub recipient: Address,
+    pub released_at: u64,
+    pub released_by: Address,
+    pub release_type: crate::ReleaseType,
+}
+
+pub fn emit_schedule_released(env: &Env, event: ScheduleReleased) {
+    let topics = (symbol_short!("sch_rel"), event.bounty_id);
+    publish_domain(env, topics, event.clone());
+}
+
+/// Event emitted when the residual left after a bounty's schedules is reclaimed.
+///
+/// # Event Topic
+/// `(symbol_short!("residual"), bounty_id)`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ResidualReclaimed {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub recipient: Address,
+    pub reclaimed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_residual_reclaimed(env: &Env, event: ResidualReclaimed) {
+    let topics = (symbol_short!("residual"), event.bounty_id);
+    publish_domain(env, topics, event.clone());
+}
+
+// ============================================================================
+// Lifecycle Envelope Event
+// ============================================================================
+
+/// Uniform envelope emitted alongside every lifecycle event.
+///
+/// The detailed events above each carry their own field set; this envelope
+/// gives indexers a single stream with the same shape for every transition.
+///
+/// # Fields
+/// * `bounty_id` - The bounty that changed
+/// * `event_type` - `lock`, `release`, `refund` or `schedule`
+/// * `actor` - Address that drove the transition
+/// * `amount` - Amount moved by the transition
+/// * `timestamp` - Ledger timestamp of the transition
+/// * `seq` - Contract-wide sequence number, strictly increasing
+///
+/// # Event Topic
+/// `(symbol_short!("lifecycle"), bounty_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LifecycleEvent {
+    pub bounty_id: u64,
+    pub event_type: Symbol,
+    pub actor: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+    pub seq: u64,
+}
+
+pub fn emit_lifecycle_event(env: &Env, event: LifecycleEvent) {
+    let topics = (symbol_short!("lifecycle"), event.bounty_id);
+    publish_domain(env, topics, event.clone());
+}
+
+/// Emitted once when a bounty reaches a terminal status (Released, Refunded,
+/// Settled, EmergencyWithdrawn or ForceClosed), whichever path got it there.
+///
+/// # Event Topic
+/// `(symbol_short!("closed"), bounty_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyClosed {
+    pub bounty_id: u64,
+    pub final_status: crate::EscrowStatus,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_closed(env: &Env, event: BountyClosed) {
+    let topics = (symbol_short!("closed"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
@@ -93,27 +93,31 @@ mod test_bounty_escrow;
 mod test_query;
 
 use events::{
-    emit_batch_funds_locked, emit_batch_funds_released, emit_bounty_initialized,
-    emit_contract_paused, emit_contract_unpaused, emit_emergency_withdrawal, emit_funds_locked,
-    emit_funds_refunded, emit_funds_released, BatchFundsLocked, BatchFundsReleased,
-    BountyEscrowInitialized, ContractPaused, ContractUnpaused, EmergencyWithdrawal, FundsLocked,
-    FundsRefunded, FundsReleased,
+    emit_batch_funds_locked, emit_batch_funds_refunded, emit_batch_funds_released,
+    emit_bounty_initialized, emit_contract_paused, emit_contract_unpaused, emit_dispute_raised,
+    emit_dispute_resolved, emit_emergency_withdrawal, emit_escrow_emergency_withdrawal,
+    emit_funds_locked, emit_funds_refunded, emit_funds_released, emit_lifecycle_event,
+    emit_max_batch_size_updated, emit_pause_scopes_updated, BatchFundsLocked, BatchFundsRefunded,
+    BatchFundsReleased, BountyEscrowInitialized, ContractPaused, ContractUnpaused, DisputeRaised,
+    DisputeResolved, EmergencyWithdrawal, FundsLocked, FundsRefunded, FundsReleased,
+    LifecycleEvent, MaxBatchSizeUpdated, PauseScopesUpdated,
 };
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, xdr::ToXdr,
+    Address, BytesN, Env, Map, String, Symbol, Vec,
 };
 
 // ==================== MONITORING MODULE ====================
 #[allow(dead_code)]
 mod monitoring {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+    use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol};
 
     // Storage keys
     const OPERATION_COUNT: &str = "op_count";
     #[allow(dead_code)]
     const USER_COUNT: &str = "usr_count";
     const ERROR_COUNT: &str = "err_count";
+    const ERROR_CODES: &str = "err_codes";
 
     // Event: Operation metric
     #[contracttype]
@@ -126,6 +130,12 @@ mod monitoring {
     }
 
     // Event: Performance metric
+    //
+    // `duration` is the ledger-timestamp difference across the call. The
+    // timestamp doesn't move within a transaction, so it is almost always
+    // 0; it only records that the function ran. A contract can't read its
+    // own CPU or memory cost on-chain (the budget is only reported in test
+    // builds), so real costs have to come from transaction simulation.
     #[contracttype]
     #[derive(Clone, Debug)]
     pub struct PerformanceMetric {
@@ -134,11 +144,23 @@ mod monitoring {
         pub timestamp: u64,
     }
 
+    // Event: Analytics reset
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub struct AnalyticsReset {
+        pub reset_by: Address,
+        pub timestamp: u64,
+    }
+
     // Data: Health status
     #[contracttype]
     #[derive(Clone, Debug)]
     pub struct HealthStatus {
         pub is_healthy: bool,
+        pub is_paused: bool,
+        pub error_rate: u32,
+        pub error_rate_exceeded: bool,
+        pub is_solvent: bool,
         pub last_operation: u64,
         pub total_operations: u64,
         pub contract_version: String,
@@ -165,6 +187,10 @@ mod monitoring {
     }
 
     // Data: Performance stats
+    //
+    // `total_time` and `avg_time` add up `PerformanceMetric::duration`, so
+    // they inherit its limits; `call_count` and `last_called` are the
+    // useful fields on-chain.
     #[contracttype]
     #[derive(Clone, Debug)]
     pub struct PerformanceStats {
@@ -176,6 +202,11 @@ mod monitoring {
     }
 
     // Track operation
+    //
+    // A call that fails (panics or returns an `Err`) has all of its storage
+    // writes and events rolled back, including the ones made here with
+    // `success = false`, so rejected calls only show up in the failed
+    // transaction results off-chain.
     pub fn track_operation(env: &Env, operation: Symbol, caller: Address, success: bool) {
         let key = Symbol::new(env, OPERATION_COUNT);
         let count: u64 = env.storage().persistent().get(&key).unwrap_or(0);
@@ -187,6 +218,9 @@ mod monitoring {
             env.storage().persistent().set(&err_key, &(err_count + 1));
         }
 
+        if crate::events::event_verbosity(env) != crate::EventVerbosity::Full {
+            return;
+        }
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("op")),
             OperationMetric {
@@ -198,10 +232,32 @@ mod monitoring {
         );
     }
 
+    // Track an error that a successful call absorbed instead of returning,
+    // e.g. an item skipped by a batch, keyed by its `Error` discriminant
+    pub fn track_error(env: &Env, code: u32) {
+        let key = Symbol::new(env, ERROR_CODES);
+        let mut codes: Map<u32, u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Map::new(env));
+        codes.set(code, codes.get(code).unwrap_or(0) + 1);
+        env.storage().persistent().set(&key, &codes);
+    }
+
+    // Get the absorbed error counts by error code
+    pub fn get_error_breakdown(env: &Env) -> Map<u32, u64> {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, ERROR_CODES))
+            .unwrap_or(Map::new(env))
+    }
+
     // Track performance
     pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
         let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
         let time_key = (Symbol::new(env, "perf_time"), function.clone());
+        let last_key = (Symbol::new(env, "perf_last"), function.clone());
 
         let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
         let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
@@ -210,7 +266,13 @@ mod monitoring {
         env.storage()
             .persistent()
             .set(&time_key, &(total + duration));
+        env.storage()
+            .persistent()
+            .set(&last_key, &env.ledger().timestamp());
 
+        if crate::events::event_verbosity(env) != crate::EventVerbosity::Full {
+            return;
+        }
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("perf")),
             PerformanceMetric {
@@ -221,14 +283,25 @@ mod monitoring {
         );
     }
 
-    // Health check
-    #[allow(dead_code)]
-    pub fn health_check(env: &Env) -> HealthStatus {
+    // Health check; unhealthy if paused, insolvent or the error rate (in
+    // basis points) is above `max_error_rate`
+    pub fn health_check(
+        env: &Env,
+        is_paused: bool,
+        is_solvent: bool,
+        max_error_rate: u32,
+    ) -> HealthStatus {
         let key = Symbol::new(env, OPERATION_COUNT);
         let ops: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+        let error_rate = get_analytics(env).error_rate;
+        let error_rate_exceeded = error_rate > max_error_rate;
 
         HealthStatus {
-            is_healthy: true,
+            is_healthy: !is_paused && is_solvent && !error_rate_exceeded,
+            is_paused,
+            error_rate,
+            error_rate_exceeded,
+            is_solvent,
             last_operation: env.ledger().timestamp(),
             total_operations: ops,
             contract_version: String::from_str(env, "1.0.0"),
@@ -275,6 +348,42 @@ mod monitoring {
         }
     }
 
+    // Reset counters and per-function performance stats
+    pub fn reset_analytics(env: &Env, admin: Address, functions: &[Symbol]) {
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, OPERATION_COUNT), &0u64);
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, USER_COUNT), &0u64);
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, ERROR_COUNT), &0u64);
+        env.storage()
+            .persistent()
+            .remove(&Symbol::new(env, ERROR_CODES));
+
+        for function in functions.iter() {
+            env.storage()
+                .persistent()
+                .remove(&(Symbol::new(env, "perf_cnt"), function.clone()));
+            env.storage()
+                .persistent()
+                .remove(&(Symbol::new(env, "perf_time"), function.clone()));
+            env.storage()
+                .persistent()
+                .remove(&(Symbol::new(env, "perf_last"), function.clone()));
+        }
+
+        env.events().publish(
+            (symbol_short!("metric"), symbol_short!("reset")),
+            AnalyticsReset {
+                reset_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
     // Get performance stats
     #[allow(dead_code)]
     pub fn get_performance_stats(env: &Env, function_name: Symbol) -> PerformanceStats {
@@ -286,7 +395,7 @@ mod monitoring {
         let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
         let last: u64 = env.storage().persistent().get(&last_key).unwrap_or(0);
 
-        let avg = if count > 0 { total / count } else { 0 };
+        let avg = total.checked_div(count).unwrap_or(0);
 
         PerformanceStats {
             function_name,
@@ -302,7 +411,7 @@ mod monitoring {
 // ==================== ANTI-ABUSE MODULE ====================
 #[allow(dead_code)]
 mod anti_abuse {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env};
+    use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
 
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
@@ -310,6 +419,8 @@ mod anti_abuse {
         pub window_size: u64,     // Window size in seconds
         pub max_operations: u32,  // Max operations allowed in window
         pub cooldown_period: u64, // Minimum seconds between operations
+        // Cap for the backed-off cooldown; 0 disables backoff
+        pub max_cooldown_period: u64,
     }
 
     #[contracttype]
@@ -318,17 +429,30 @@ mod anti_abuse {
         pub last_operation_timestamp: u64,
         pub window_start_timestamp: u64,
         pub operation_count: u32,
+        // Consecutive windows that used up `max_operations`, drives the backoff
+        pub violation_count: u32,
     }
 
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
     pub enum AntiAbuseKey {
         Config,
-        State(Address),
+        OperationConfig(Symbol),
+        State(Address, Symbol),
         Whitelist(Address),
+        Blacklist(Address),
         Admin,
     }
 
+    // Event: Address banned
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct AbuseBlacklisted {
+        pub address: Address,
+        pub banned_by: Address,
+        pub timestamp: u64,
+    }
+
     pub fn get_config(env: &Env) -> AntiAbuseConfig {
         env.storage()
             .instance()
@@ -337,6 +461,7 @@ mod anti_abuse {
                 window_size: 3600, // 1 hour default
                 max_operations: 10,
                 cooldown_period: 60, // 1 minute default
+                max_cooldown_period: 0,
             })
     }
 
@@ -345,6 +470,20 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Config, &config);
     }
 
+    /// Config for a single operation, falling back to the global default
+    pub fn get_config_for_operation(env: &Env, operation: Symbol) -> AntiAbuseConfig {
+        env.storage()
+            .instance()
+            .get(&AntiAbuseKey::OperationConfig(operation))
+            .unwrap_or_else(|| get_config(env))
+    }
+
+    pub fn set_config_for_operation(env: &Env, operation: Symbol, config: AntiAbuseConfig) {
+        env.storage()
+            .instance()
+            .set(&AntiAbuseKey::OperationConfig(operation), &config);
+    }
+
     pub fn is_whitelisted(env: &Env, address: Address) -> bool {
         env.storage()
             .instance()
@@ -364,6 +503,56 @@ mod anti_abuse {
         }
     }
 
+    /// Applies `whitelisted` to every address in `addresses` and emits a
+    /// single summary event. Returns how many addresses actually changed.
+    pub fn set_whitelist_batch(
+        env: &Env,
+        admin: Address,
+        addresses: Vec<Address>,
+        whitelisted: bool,
+    ) -> u32 {
+        let mut changed = 0u32;
+        for address in addresses.iter() {
+            if is_whitelisted(env, address.clone()) != whitelisted {
+                set_whitelist(env, address, whitelisted);
+                changed += 1;
+            }
+        }
+
+        env.events().publish(
+            (symbol_short!("abuse"), symbol_short!("wl_batch")),
+            (admin, whitelisted, changed, env.ledger().timestamp()),
+        );
+
+        changed
+    }
+
+    pub fn is_blacklisted(env: &Env, address: Address) -> bool {
+        env.storage()
+            .instance()
+            .has(&AntiAbuseKey::Blacklist(address))
+    }
+
+    pub fn set_blacklist(env: &Env, admin: Address, address: Address, banned: bool) {
+        if banned {
+            env.storage()
+                .instance()
+                .set(&AntiAbuseKey::Blacklist(address.clone()), &true);
+            env.events().publish(
+                (symbol_short!("abuse"), symbol_short!("blacklist")),
+                AbuseBlacklisted {
+                    address,
+                    banned_by: admin,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        } else {
+            env.storage()
+                .instance()
+                .remove(&AntiAbuseKey::Blacklist(address));
+        }
+    }
+
     #[allow(dead_code)]
     pub fn get_admin(env: &Env) -> Option<Address> {
         env.storage().instance().get(&AntiAbuseKey::Admin)
@@ -374,14 +563,89 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Admin, &admin);
     }
 
-    pub fn check_rate_limit(env: &Env, address: Address) {
+    pub fn get_state(env: &Env, address: Address, operation: Symbol) -> AddressState {
+        env.storage()
+            .persistent()
+            .get(&AntiAbuseKey::State(address, operation))
+            .unwrap_or(AddressState {
+                last_operation_timestamp: 0,
+                window_start_timestamp: 0,
+                operation_count: 0,
+                violation_count: 0,
+            })
+    }
+
+    /// Cooldown that currently applies to an address. With backoff enabled
+    /// it is `cooldown_period * 2^violation_count`, capped at
+    /// `max_cooldown_period`.
+    pub fn effective_cooldown(config: &AntiAbuseConfig, state: &AddressState) -> u64 {
+        if config.max_cooldown_period == 0 {
+            return config.cooldown_period;
+        }
+
+        1u64.checked_shl(state.violation_count)
+            .and_then(|factor| config.cooldown_period.checked_mul(factor))
+            .unwrap_or(u64::MAX)
+            .min(config.max_cooldown_period.max(config.cooldown_period))
+    }
+
+    /// Drops the stored state of `address` for each of `operations`,
+    /// resetting its cooldown and window counters.
+    pub fn clear_state(env: &Env, admin: Address, address: Address, operations: &[Symbol]) {
+        for operation in operations.iter() {
+            env.storage()
+                .persistent()
+                .remove(&AntiAbuseKey::State(address.clone(), operation.clone()));
+        }
+
+        env.events().publish(
+            (symbol_short!("abuse"), symbol_short!("cleared")),
+            (admin, address, env.ledger().timestamp()),
+        );
+    }
+
+    /// Seconds until `address` may perform `operation` again without tripping
+    /// the cooldown or the window limit. Whitelisted addresses never wait.
+    pub fn seconds_until_allowed(env: &Env, address: Address, operation: Symbol) -> u64 {
+        if is_whitelisted(env, address.clone()) {
+            return 0;
+        }
+
+        let config = get_config_for_operation(env, operation.clone());
+        let state = get_state(env, address, operation);
+        let now = env.ledger().timestamp();
+
+        let mut wait = 0;
+        if state.last_operation_timestamp > 0 {
+            let cooldown_end = state
+                .last_operation_timestamp
+                .saturating_add(effective_cooldown(&config, &state));
+            wait = cooldown_end.saturating_sub(now);
+        }
+
+        let window_end = state
+            .window_start_timestamp
+            .saturating_add(config.window_size);
+        if state.operation_count >= config.max_operations && now < window_end {
+            wait = wait.max(window_end - now);
+        }
+
+        wait
+    }
+
+    pub fn check_rate_limit(env: &Env, address: Address, operation: Symbol) {
+        // Banned addresses are rejected outright, even if whitelisted
+        if is_blacklisted(env, address.clone()) {
+            panic!("Address blacklisted");
+        }
+
         if is_whitelisted(env, address.clone()) {
             return;
         }
 
-        let config = get_config(env);
+        let config = get_config_for_operation(env, operation.clone());
         let now = env.ledger().timestamp();
-        let key = AntiAbuseKey::State(address.clone());
+        let key = AntiAbuseKey::State(address.clone(), operation);
 
         let mut state: AddressState =
             env.storage()
@@ -391,14 +655,13 @@ mod anti_abuse {
                     last_operation_timestamp: 0,
                     window_start_timestamp: now,
                     operation_count: 0,
+                    violation_count: 0,
                 });
 
         // 1. Cooldown check
+        let cooldown = effective_cooldown(&config, &state);
         if state.last_operation_timestamp > 0
-            && now
-                < state
-                    .last_operation_timestamp
-                    .saturating_add(config.cooldown_period)
+            && now < state.last_operation_timestamp.saturating_add(cooldown)
         {
             env.events().publish(
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
@@ -413,7 +676,21 @@ mod anti_abuse {
                 .window_start_timestamp
                 .saturating_add(config.window_size)
         {
-            // New window
+            // New window. A rejected call rolls back everything it wrote, so
+            // the violation is recorded here instead: a previous window that
+            // used up its budget escalates the backoff, a clean one resets it.
+            if config.max_cooldown_period > 0
+                && state.last_operation_timestamp > 0
+                && state.operation_count >= config.max_operations
+            {
+                state.violation_count = state.violation_count.saturating_add(1);
+                env.events().publish(
+                    (symbol_short!("abuse"), symbol_short!("backoff")),
+                    (address.clone(), now, effective_cooldown(&config, &state)),
+                );
+            } else {
+                state.violation_count = 0;
+            }
             state.window_start_timestamp = now;
             state.operation_count = 1;
         } else {
@@ -437,6 +714,74 @@ mod anti_abuse {
 }
 // ==================== END ANTI-ABUSE MODULE ====================
 
+// ==================== ROLES MODULE ====================
+mod roles {
+    use soroban_sdk::{contracttype, symbol_short, Address, Env};
+
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Role {
+        Pauser,
+        FeeManager,
+        Releaser,
+        ScheduleManager,
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub enum RoleKey {
+        Member(Role, Address),
+    }
+
+    // Event: Role granted or revoked
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct RoleChanged {
+        pub role: Role,
+        pub address: Address,
+        pub changed_by: Address,
+        pub timestamp: u64,
+    }
+
+    /// Explicit membership only; callers treat the admin as holding every role.
+    pub fn is_member(env: &Env, role: Role, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&RoleKey::Member(role, address))
+    }
+
+    pub fn grant(env: &Env, admin: Address, role: Role, address: Address) {
+        env.storage()
+            .persistent()
+            .set(&RoleKey::Member(role, address.clone()), &true);
+        env.events().publish(
+            (symbol_short!("role"), symbol_short!("granted")),
+            RoleChanged {
+                role,
+                address,
+                changed_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    pub fn revoke(env: &Env, admin: Address, role: Role, address: Address) {
+        env.storage()
+            .persistent()
+            .remove(&RoleKey::Member(role, address.clone()));
+        env.events().publish(
+            (symbol_short!("role"), symbol_short!("revoked")),
+            RoleChanged {
+                role,
+                address,
+                changed_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+}
+// ==================== END ROLES MODULE ====================
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -476,6 +821,97 @@ pub enum Error {
     /// Returned when refund is attempted without admin approval
     RefundNotApproved = 17,
     BatchSizeMismatch = 18,
+    /// Returned when a transfer would send funds to the contract's own address
+    InvalidRecipient = 19,
+
+    /// Returned when locking from a template that doesn't exist
+    TemplateNotFound = 20,
+
+    /// Returned when a refund mode isn't allowed by the bounty's template
+    RefundModeNotAllowed = 21,
+
+    /// Returned when a release schedule doesn't exist for the bounty
+    ScheduleNotFound = 22,
+
+    /// Returned when a release schedule has already been executed
+    ScheduleAlreadyReleased = 23,
+
+    /// Returned when an automatic release is attempted before the schedule is due
+    ScheduleNotDue = 24,
+
+    /// Returned when a queued admin action doesn't exist
+    ActionNotFound = 25,
+
+    /// Returned when a queued admin action is executed before its time-lock expires
+    ActionNotReady = 26,
+
+    /// Returned when a queued admin action was already executed or cancelled
+    ActionAlreadyExecuted = 27,
+
+    /// Returned when a signed release reuses a nonce
+    NonceAlreadyUsed = 28,
+
+    /// Returned when a signed release is attempted before a signing key is set
+    SigningKeyNotSet = 29,
+
+    /// Returned when a signer approves the same release twice
+    AlreadyApproved = 30,
+
+    /// Returned when a multisig release has fewer approvals than the threshold
+    InsufficientApprovals = 31,
+
+    /// Returned when a multisig threshold is zero, exceeds the signer count,
+    /// or the signer list has duplicates
+    InvalidThreshold = 32,
+
+    /// Returned when accepting an admin handover that was never proposed
+    NoPendingAdmin = 33,
+
+    /// Returned when a custom refund uses an approval past its `expires_at`
+    ApprovalExpired = 34,
+
+    /// Returned when releasing to a contributor who hasn't acknowledged the
+    /// bounty while acknowledgement is required
+    ContributorNotAcknowledged = 35,
+
+    /// Returned when releasing or refunding a bounty under dispute
+    BountyDisputed = 36,
+
+    /// Returned when resolving a dispute on a bounty that isn't disputed
+    NoActiveDispute = 37,
+
+    /// Returned when a milestone doesn't exist for the bounty
+    MilestoneNotFound = 38,
+
+    /// Returned when releasing a milestone the admin hasn't approved
+    MilestoneNotApproved = 39,
+
+    /// Returned when a milestone has already been released
+    MilestoneAlreadyReleased = 40,
+
+    /// Returned when reclaiming a residual while schedules are still pending
+    SchedulesPending = 41,
+
+    /// Returned when a lock reuses an idempotency key that already succeeded
+    DuplicateRequest = 42,
+
+    /// Returned when a partial or custom refund would leave less than the
+    /// amount committed to pending release schedules
+    FundsCommittedToSchedule = 43,
+
+    /// Returned when a permissionless refund is attempted during the grace
+    /// period after the deadline
+    InGracePeriod = 44,
+
+    /// Returned when a queued admin action is executed after its expiry
+    ActionExpired = 45,
+
+    /// Returned when another designated contributor has already
+    /// acknowledged the bounty
+    AlreadyAcknowledged = 46,
+
+    /// Returned when the force-close delay is set below the minimum
+    ForceCloseDelayTooShort = 47,
 }
 
 // ============================================================================
@@ -486,19 +922,26 @@ pub enum Error {
 ///
 /// # State Transitions
 /// ```text
-/// NONE → Locked → Released (final)
-///           ↓
-///        Refunded (final)
+/// NONE → Locked ──────────────────────────→ Released | Refunded | Settled
+///           │                                EmergencyWithdrawn | ForceClosed
+///           ↓                                         ↑
+///        PartiallyReleased / PartiallyRefunded ───────┘
 /// ```
 ///
 /// # States
 /// * `Locked` - Funds are held in escrow, awaiting release or refund
+/// * `PartiallyReleased` - Part of the funds went to contributors, the rest is still held
+/// * `PartiallyRefunded` - Part of the funds went back to the depositor, the rest is still held
 /// * `Released` - Funds have been transferred to contributor (final state)
 /// * `Refunded` - Funds have been returned to depositor (final state)
+/// * `Settled` - Admin split the funds between contributor and depositor (final state)
+/// * `EmergencyWithdrawn` - Remaining funds were pulled out by an emergency withdrawal (final state)
+/// * `ForceClosed` - Funds left after the force-close delay were sent to a treasury (final state)
 ///
 /// # Invariants
-/// - Once in Released or Refunded state, no further transitions allowed
-/// - Only Locked state allows state changes
+/// - Once in Released, Refunded, Settled, EmergencyWithdrawn or ForceClosed
+///   state, no further transitions allowed
+/// - Only Locked, PartiallyReleased and PartiallyRefunded allow state changes
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum EscrowStatus {
@@ -507,6 +950,31 @@ pub enum EscrowStatus {
     Refunded,
     PartiallyRefunded,
     PartiallyReleased,
+    Settled,
+    EmergencyWithdrawn,
+    ForceClosed,
+}
+
+/// How much the contract emits, set with `set_event_verbosity`.
+/// `DomainOnly` drops the monitoring metric events; `Minimal` also drops
+/// every domain event except `BountyClosed`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventVerbosity {
+    Full,
+    DomainOnly,
+    Minimal,
+}
+
+/// Groups of operations that can be paused independently with
+/// `pause_operations`. A full `pause` covers every scope.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseScope {
+    Lock,
+    Release,
+    Refund,
+    Schedule,
 }
 
 #[contracttype]
@@ -523,6 +991,7 @@ pub struct PayoutRecord {
     pub amount: i128,
     pub recipient: Address,
     pub timestamp: u64,
+    pub memo: Option<String>, // Reason given by whoever released, if any
 }
 
 #[contracttype]
@@ -532,6 +1001,64 @@ pub struct RefundRecord {
     pub recipient: Address,
     pub mode: RefundMode,
     pub timestamp: u64,
+    pub memo: Option<String>, // Reason given by whoever refunded, if any
+}
+
+/// A named slice of a bounty, paid out by `release_milestone` once the
+/// admin has approved it. Unreleased milestones never add up to more than
+/// the escrow's remaining amount when they are created.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub id: u32,
+    pub amount: i128,
+    pub description: String,
+    pub approved: bool,
+    pub released: bool,
+}
+
+/// A time-based release of part of a bounty's escrow.
+///
+/// Scheduled amounts stay reserved in the escrow until the schedule is
+/// executed, either automatically once due or manually by the admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseSchedule {
+    pub schedule_id: u64,
+    pub amount: i128,
+    pub release_timestamp: u64,
+    pub recipient: Address,
+    pub released: bool,
+    pub released_at: Option<u64>,
+    pub released_by: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReleaseType {
+    Automatic,
+    Manual,
+}
+
+/// One entry in a bounty's status history, see `get_status_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusChange {
+    pub status: EscrowStatus,
+    pub timestamp: u64,
+    pub actor: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseHistory {
+    pub schedule_id: u64,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub recipient: Address,
+    pub released_at: u64,
+    pub released_by: Address,
+    pub release_type: ReleaseType,
 }
 
 #[contracttype]
@@ -543,6 +1070,8 @@ pub struct RefundApproval {
     pub mode: RefundMode,
     pub approved_by: Address,
     pub approved_at: u64,
+    pub expires_at: u64,
+    pub nonce: u64, // must equal the bounty's current approval nonce to be used
 }
 
 /// Complete escrow record for a bounty.
@@ -552,6 +1081,10 @@ pub struct RefundApproval {
 /// * `amount` - Token amount held in escrow (in smallest denomination)
 /// * `status` - Current state of the escrow (Locked/Released/Refunded)
 /// * `deadline` - Unix timestamp after which refunds are allowed
+/// * `token` - Asset the funds are held in; the contract token unless the
+///   bounty was locked with `lock_funds_with_token`
+/// * `disputed` - Set by `raise_dispute`; freezes releases and refunds until
+///   the admin calls `resolve_dispute`
 ///
 /// # Storage
 /// Stored in persistent storage with key `DataKey::Escrow(bounty_id)`.
@@ -576,6 +1109,18 @@ pub struct Escrow {
     pub refund_history: Vec<RefundRecord>,
     pub payout_history: Vec<PayoutRecord>,
     pub remaining_amount: i128,
+    pub token: Address,
+    pub disputed: bool,
+}
+
+/// What `lock_funds_v2` locked, so clients don't have to query the escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockReceipt {
+    pub bounty_id: u64,
+    pub net_amount: i128, // Amount held in escrow, after any lock fee
+    pub deadline: u64,
+    pub locked_at: u64,
 }
 
 /// Storage keys for contract data.
@@ -604,65 +1149,209 @@ pub struct ReleaseFundsItem {
     pub contributor: Address,
 }
 
-// Maximum batch size to prevent gas limit issues
-const MAX_BATCH_SIZE: u32 = 100;
+// Batch size limit used until the admin sets one, to prevent gas limit issues
+const DEFAULT_MAX_BATCH_SIZE: u32 = 100;
+// Highest batch size limit the admin may configure
+const MAX_BATCH_SIZE_CAP: u32 = 200;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeConfig {
     pub lock_fee_rate: i128, // Fee rate for lock operations (basis points, e.g., 100 = 1%)
     pub release_fee_rate: i128, // Fee rate for release operations (basis points)
+    pub refund_fee_rate: i128, // Fee rate for refund operations (basis points)
     pub fee_recipient: Address, // Address to receive fees
     pub fee_enabled: bool,   // Global fee enable/disable flag
 }
 
-// Fee rate is stored in basis points (1 basis point = 0.01%)
-// Example: 100 basis points = 1%, 1000 basis points = 10%
-const BASIS_POINTS: i128 = 10_000;
-const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
-
+/// Requested fee changes; `None` fields keep their current value.
 #[contracttype]
-pub enum DataKey {
-    Admin,
-    Token,
-    Escrow(u64),         // bounty_id
-    FeeConfig,           // Fee configuration
-    RefundApproval(u64), // bounty_id -> RefundApproval
-    ReentrancyGuard,
-    IsPaused,       // Contract pause state
-    BountyRegistry, // Vec<u64> of all bounty IDs
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfigUpdate {
+    pub lock_fee_rate: Option<i128>,
+    pub release_fee_rate: Option<i128>,
+    pub refund_fee_rate: Option<i128>,
+    pub fee_recipient: Option<Address>,
+    pub fee_enabled: Option<bool>,
 }
 
+/// Bounds applied to new locks; `None` means unbounded.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct EscrowFilter {
-    pub status: Option<u32>, // Using u32 to avoid Option<Enum> XDR issues
-    pub depositor: Option<Address>,
-    pub min_amount: Option<i128>,
-    pub max_amount: Option<i128>,
-    pub start_time: Option<u64>, // Filter by deadline (>= start_time)
-    pub end_time: Option<u64>,   // Filter by deadline (<= end_time)
+pub struct ConfigLimits {
+    pub min_bounty_amount: Option<i128>,
+    pub max_bounty_amount: Option<i128>,
+    pub min_deadline_duration: Option<u64>,
+    pub max_deadline_duration: Option<u64>,
 }
 
+/// N-of-M signer set that must approve every payout from escrow.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Pagination {
-    pub start_index: u64,
-    pub limit: u32,
+pub struct MultisigConfig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
 }
 
+/// Signer approvals for one payout of a bounty, bound to its recipient and
+/// amount.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct EscrowStats {
-    pub total_bounties: u64,
-    pub total_locked_amount: i128,
-    pub total_released_amount: i128,
-    pub total_refunded_amount: i128,
+pub struct ReleaseApproval {
+    pub recipient: Address,
+    pub amount: i128,
+    pub signers: Vec<Address>,
 }
 
-// ============================================================================
-// Contract Implementation
-// ============================================================================
+/// A sensitive config change routed through the admin action queue.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminActionType {
+    Admin(Address),
+    PayoutKey(Address),
+    ConfigLimits(ConfigLimits),
+    FeeConfig(FeeConfigUpdate),
+    TimeLock(u64),
+    SigningKey(BytesN<32>),
+    Signers(MultisigConfig),
+    Governance(Address),
+    ForceCloseDelay(u64),
+    PendingAdmin(Address),
+}
+
+/// A queued admin config change.
+///
+/// Once `execute_after` is reached the admin can apply it with
+/// `execute_admin_action`, until `expires_at`; after that it has to be
+/// proposed again. With a zero time-lock, changes apply immediately and are
+/// never queued.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminAction {
+    pub action_id: u64,
+    pub action_type: AdminActionType,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    pub execute_after: u64,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
+/// Named preset of escrow parameters shared by many bounties.
+///
+/// * `default_duration` - Seconds from lock time until the deadline
+/// * `tag` - Free-form label for off-chain grouping
+/// * `allowed_refund_modes` - Refund modes permitted for bounties locked from it
+/// * `fee_exempt` - Skip the lock fee for bounties locked from it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowTemplate {
+    pub name: Symbol,
+    pub default_duration: u64,
+    pub tag: Symbol,
+    pub allowed_refund_modes: Vec<RefundMode>,
+    pub fee_exempt: bool,
+}
+
+// Fee rate is stored in basis points (1 basis point = 0.01%)
+// Example: 100 basis points = 1%, 1000 basis points = 10%
+const BASIS_POINTS: i128 = 10_000;
+const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
+const DEFAULT_REFUND_APPROVAL_WINDOW: u64 = 7 * 24 * 60 * 60; // 7 days
+const IDEMPOTENCY_KEY_TTL: u32 = 17280; // ~1 day of ledgers
+const DEFAULT_MAX_ERROR_RATE: u32 = 1_000; // 10%, in basis points
+const DEFAULT_FORCE_CLOSE_DELAY: u64 = 365 * 24 * 60 * 60; // 1 year
+const MIN_FORCE_CLOSE_DELAY: u64 = 30 * 24 * 60 * 60; // 30 days
+const DEFAULT_ADMIN_ACTION_EXPIRY: u64 = 7 * 24 * 60 * 60; // 7 days
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    Escrow(u64),                // bounty_id
+    FeeConfig,                  // Fee configuration
+    RefundApproval(u64),        // bounty_id -> RefundApproval
+    ReentrancyGuard(Symbol),    // operation -> true while it is in progress
+    IsPaused,                   // Contract pause state
+    BountyRegistry,             // Vec<u64> of all bounty IDs
+    Template(Symbol),           // template name -> EscrowTemplate
+    BountyTemplate(u64),        // bounty_id -> template name
+    EventSequence,              // u64 sequence of lifecycle envelopes
+    ReleaseSchedule(u64, u64),  // (bounty_id, schedule_id) -> ReleaseSchedule
+    NextScheduleId(u64),        // bounty_id -> next schedule id
+    ScheduleIds(u64),           // bounty_id -> Vec<u64> of stored schedule ids, ascending
+    ReleaseHistory(u64),        // bounty_id -> Vec<ReleaseHistory>
+    PayoutKey,                  // Address allowed to authorize payouts
+    ConfigLimits,               // ConfigLimits applied to new locks
+    TimeLockDuration,           // u64 seconds admin actions wait before executing
+    AdminAction(u64),           // action_id -> AdminAction
+    NextActionId,               // u64 id of the next queued admin action
+    AdminActionExpiry,          // u64 seconds a ready admin action stays executable
+    PendingActionIds,           // Vec<u64> of queued admin actions not yet executed or cancelled
+    SigningKey,                 // BytesN<32> ed25519 key that signs relayed releases
+    UsedNonce(u64),             // nonce -> true once consumed by a signed release
+    MultisigConfig,             // MultisigConfig required for releases
+    ReleaseApprovals(u64),      // bounty_id -> ReleaseApproval for its next payout
+    PendingAdmin,               // Address proposed via propose_new_admin
+    Governance,                 // Address that alone may change fees and config limits
+    PausedScopes,               // u32 bitmask of individually paused PauseScope values
+    StatusHistory(u64),         // bounty_id -> Vec<StatusChange>, one entry per transition
+    RefundApprovalWindow,       // u64 seconds a refund approval stays usable
+    RequireAcknowledgement,     // bool, releases need the contributor's acknowledgement
+    Acknowledgement(u64),       // bounty_id -> Address of the contributor who acknowledged
+    Milestones(u64),            // bounty_id -> Vec<Milestone>, ids ascending from 1
+    RefundRequiresDepositor,    // bool, refunds need the depositor's auth
+    IdempotencyKey(BytesN<32>), // key -> bounty_id it locked (temporary storage)
+    AllowedRecipients(u64),     // bounty_id -> Vec<Address> the depositor allows payouts to
+    EventVerbosity,             // EventVerbosity, Full when unset
+    MaxErrorRate,               // u32 basis points above which health_check reports unhealthy
+    RefundApprovalNonce(u64),   // bounty_id -> u64 nonce of the latest refund approval
+    RefundGracePeriod,          // u64 seconds after the deadline when only the admin can refund
+    ForceCloseDelay,            // u64 seconds after the deadline before force_close is allowed
+    TokenDecimals,              // u32 decimals of the escrow token, when known
+    MaxBatchSize,               // u32 item limit for batch operations
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowFilter {
+    pub statuses: Vec<u32>, // EscrowStatus as u32; matches any entry, empty matches all
+    pub depositor: Option<Address>,
+    pub min_amount: Option<i128>,
+    pub max_amount: Option<i128>,
+    pub start_time: Option<u64>, // Filter by deadline (>= start_time)
+    pub end_time: Option<u64>,   // Filter by deadline (<= end_time)
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortBy {
+    BountyId,
+    Amount,
+    Deadline,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pagination {
+    pub start_index: u64,
+    pub limit: u32,
+    pub sort_by: SortBy, // BountyId ascending keeps registry order
+    pub descending: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowStats {
+    pub total_bounties: u64,
+    pub total_locked_amount: i128,
+    pub total_released_amount: i128,
+    pub total_refunded_amount: i128,
+}
+
+// ============================================================================
+// Contract Implementation
+// ============================================================================
 
 #[contract]
 pub struct BountyEscrowContract;
@@ -709,7 +1398,7 @@ impl BountyEscrowContract {
     /// Low - Only two storage writes
     pub fn init(env: Env, admin: Address, token: Address) -> Result<(), Error> {
         // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, admin.clone());
+        anti_abuse::check_rate_limit(&env, admin.clone(), symbol_short!("init"));
 
         let start = env.ledger().timestamp();
         let caller = admin.clone();
@@ -723,11 +1412,21 @@ impl BountyEscrowContract {
         // Store configuration
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Token, &token);
+        anti_abuse::set_admin(&env, admin.clone());
+
+        // Remember the token's decimals if it exposes them; otherwise the
+        // admin can supply them later with `set_token_decimals`.
+        if let Ok(Ok(decimals)) = token::Client::new(&env, &token).try_decimals() {
+            env.storage()
+                .instance()
+                .set(&DataKey::TokenDecimals, &decimals);
+        }
 
         // Initialize fee config with zero fees (disabled by default)
         let fee_config = FeeConfig {
             lock_fee_rate: 0,
             release_fee_rate: 0,
+            refund_fee_rate: 0,
             fee_recipient: admin.clone(),
             fee_enabled: false,
         };
@@ -776,66 +1475,120 @@ impl BountyEscrowContract {
             .unwrap_or_else(|| FeeConfig {
                 lock_fee_rate: 0,
                 release_fee_rate: 0,
+                refund_fee_rate: 0,
                 fee_recipient: env.storage().instance().get(&DataKey::Admin).unwrap(),
                 fee_enabled: false,
             })
     }
 
-    /// Update fee configuration (admin only)
+    /// Emits the uniform lifecycle envelope with the next sequence number
+    fn emit_lifecycle(env: &Env, bounty_id: u64, event_type: Symbol, actor: Address, amount: i128) {
+        let seq: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::EventSequence)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::EventSequence, &(seq + 1));
+
+        emit_lifecycle_event(
+            env,
+            LifecycleEvent {
+                bounty_id,
+                event_type,
+                actor,
+                amount,
+                timestamp: env.ledger().timestamp(),
+                seq,
+            },
+        );
+    }
+
+    /// Appends a transition to the bounty's status history. A bounty only goes
+    /// through a handful of transitions, so the list stays small.
+    fn record_status_change(env: &Env, bounty_id: u64, status: EscrowStatus, actor: Address) {
+        let key = DataKey::StatusHistory(bounty_id);
+        let mut history: Vec<StatusChange> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        history.push_back(StatusChange {
+            status,
+            timestamp: env.ledger().timestamp(),
+            actor,
+        });
+        env.storage().persistent().set(&key, &history);
+
+        // Terminal statuses are never left, so this fires once per bounty
+        if matches!(
+            status,
+            EscrowStatus::Released
+                | EscrowStatus::Refunded
+                | EscrowStatus::Settled
+                | EscrowStatus::EmergencyWithdrawn
+                | EscrowStatus::ForceClosed
+        ) {
+            events::emit_bounty_closed(
+                env,
+                events::BountyClosed {
+                    bounty_id,
+                    final_status: status,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+    }
+
+    /// Update fee configuration (admin or `FeeManager`, or only the
+    /// governance contract once `set_governance` is in effect)
+    ///
+    /// Applies immediately when no time-lock is configured or the caller is
+    /// governance; otherwise the change is queued and its action id returned
+    /// for `execute_admin_action`.
     pub fn update_fee_config(
         env: Env,
+        caller: Address,
         lock_fee_rate: Option<i128>,
         release_fee_rate: Option<i128>,
+        refund_fee_rate: Option<i128>,
         fee_recipient: Option<Address>,
         fee_enabled: Option<bool>,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        let mut fee_config = Self::get_fee_config_internal(&env);
-
-        if let Some(rate) = lock_fee_rate {
-            if !(0..=MAX_FEE_RATE).contains(&rate) {
-                return Err(Error::InvalidFeeRate);
+    ) -> Result<Option<u64>, Error> {
+        let governance = Self::get_governance(env.clone());
+        match &governance {
+            Some(governance) => {
+                if &caller != governance {
+                    return Err(Error::Unauthorized);
+                }
+                caller.require_auth();
             }
-            fee_config.lock_fee_rate = rate;
+            None => Self::require_role(&env, roles::Role::FeeManager, &caller)?,
         }
 
-        if let Some(rate) = release_fee_rate {
+        for rate in [lock_fee_rate, release_fee_rate, refund_fee_rate]
+            .into_iter()
+            .flatten()
+        {
             if !(0..=MAX_FEE_RATE).contains(&rate) {
                 return Err(Error::InvalidFeeRate);
             }
-            fee_config.release_fee_rate = rate;
-        }
-
-        if let Some(recipient) = fee_recipient {
-            fee_config.fee_recipient = recipient;
         }
 
-        if let Some(enabled) = fee_enabled {
-            fee_config.fee_enabled = enabled;
+        let update = FeeConfigUpdate {
+            lock_fee_rate,
+            release_fee_rate,
+            refund_fee_rate,
+            fee_recipient,
+            fee_enabled,
+        };
+        let action_type = AdminActionType::FeeConfig(update);
+        if governance.is_some() {
+            Self::apply_admin_action(&env, &action_type);
+            return Ok(None);
         }
-
-        env.storage()
-            .instance()
-            .set(&DataKey::FeeConfig, &fee_config);
-
-        events::emit_fee_config_updated(
-            &env,
-            events::FeeConfigUpdated {
-                lock_fee_rate: fee_config.lock_fee_rate,
-                release_fee_rate: fee_config.release_fee_rate,
-                fee_recipient: fee_config.fee_recipient.clone(),
-                fee_enabled: fee_config.fee_enabled,
-                timestamp: env.ledger().timestamp(),
-            },
-        );
-
-        Ok(())
+        Ok(Self::submit_admin_action(&env, caller, action_type))
     }
 
     /// Get current fee configuration (view function)
@@ -844,1325 +1597,5203 @@ impl BountyEscrowContract {
     }
 
     // ========================================================================
-    // Pause and Emergency Functions
+    // Time-Locked Admin Configuration
     // ========================================================================
 
-    /// Check if contract is paused (internal helper)
-    fn is_paused_internal(env: &Env) -> bool {
-        env.storage()
-            .persistent()
-            .get::<_, bool>(&DataKey::IsPaused)
-            .unwrap_or(false)
+    /// Replaces the admin (admin only). Goes through the time-lock queue.
+    ///
+    /// Prefer `propose_new_admin` + `accept_admin`, which proves the new
+    /// admin controls a working key before the old one loses access.
+    pub fn update_admin(env: Env, new_admin: Address) -> Result<Option<u64>, Error> {
+        let admin = Self::require_admin(&env)?;
+        Ok(Self::submit_admin_action(
+            &env,
+            admin,
+            AdminActionType::Admin(new_admin),
+        ))
     }
 
-    /// Get pause status (view function)
-    pub fn is_paused(env: Env) -> bool {
-        Self::is_paused_internal(&env)
+    /// Starts a two-step admin handover (admin only). The new admin takes
+    /// over once it calls `accept_admin`. Proposing again replaces the
+    /// pending address. Goes through the time-lock queue, so the handover
+    /// can't be accepted before the time-lock has passed.
+    ///
+    /// # Events
+    /// Emits: `AdminProposed { current_admin, pending_admin, timestamp }`
+    /// once the pending admin is set
+    pub fn propose_new_admin(env: Env, new_admin: Address) -> Result<Option<u64>, Error> {
+        let admin = Self::require_admin(&env)?;
+        Ok(Self::submit_admin_action(
+            &env,
+            admin,
+            AdminActionType::PendingAdmin(new_admin),
+        ))
     }
 
-    /// Pause the contract (admin only)
-    /// Prevents new fund locks, releases, and refunds
-    pub fn pause(env: Env) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    /// Completes a handover started by `propose_new_admin`. Requires the
+    /// pending admin's own authorization.
+    ///
+    /// # Returns
+    /// * `Err(Error::NoPendingAdmin)` - No handover was proposed
+    ///
+    /// # Events
+    /// Emits: `AdminUpdated { old_admin, new_admin, timestamp }`
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(Error::NoPendingAdmin)?;
+        pending.require_auth();
 
-        if Self::is_paused_internal(&env) {
-            return Ok(()); // Already paused, idempotent
-        }
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        Self::set_admin_internal(&env, pending);
+        Ok(())
+    }
 
-        env.storage().persistent().set(&DataKey::IsPaused, &true);
+    /// Get the admin proposed via `propose_new_admin`, if any (view function)
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
 
-        emit_contract_paused(
+    /// Sets the payout key (admin only). Goes through the time-lock queue.
+    pub fn update_payout_key(env: Env, payout_key: Address) -> Result<Option<u64>, Error> {
+        let admin = Self::require_admin(&env)?;
+        Ok(Self::submit_admin_action(
             &env,
-            ContractPaused {
-                paused_by: admin.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
-
-        Ok(())
+            admin,
+            AdminActionType::PayoutKey(payout_key),
+        ))
     }
 
-    /// Unpause the contract (admin only)
-    /// Resumes normal operations
-    pub fn unpause(env: Env) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
+    /// Sets the amount and deadline limits for new locks (admin only).
+    /// Goes through the time-lock queue. Once `set_governance` is in effect
+    /// only the governance contract can call this, and the change applies
+    /// immediately.
+    ///
+    /// # Returns
+    /// * `Err(Error::InvalidAmount)` - Non-positive bounds or min above max
+    /// * `Err(Error::InvalidDeadline)` - Minimum duration above the maximum
+    pub fn update_config_limits(env: Env, limits: ConfigLimits) -> Result<Option<u64>, Error> {
+        let governance = Self::get_governance(env.clone());
+        let caller = match &governance {
+            Some(governance) => {
+                governance.require_auth();
+                governance.clone()
+            }
+            None => Self::require_admin(&env)?,
+        };
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        if limits.min_bounty_amount.is_some_and(|min| min <= 0)
+            || limits.max_bounty_amount.is_some_and(|max| max <= 0)
+        {
+            return Err(Error::InvalidAmount);
+        }
+        if let (Some(min), Some(max)) = (limits.min_bounty_amount, limits.max_bounty_amount) {
+            if min > max {
+                return Err(Error::InvalidAmount);
+            }
+        }
+        if let (Some(min), Some(max)) = (limits.min_deadline_duration, limits.max_deadline_duration)
+        {
+            if min > max {
+                return Err(Error::InvalidDeadline);
+            }
+        }
 
-        if !Self::is_paused_internal(&env) {
-            return Ok(()); // Already unpaused, idempotent
+        let action_type = AdminActionType::ConfigLimits(limits);
+        if governance.is_some() {
+            Self::apply_admin_action(&env, &action_type);
+            return Ok(None);
         }
+        Ok(Self::submit_admin_action(&env, caller, action_type))
+    }
 
-        env.storage().persistent().set(&DataKey::IsPaused, &false);
+    /// Same as `update_config_limits`, but the amount bounds are given in
+    /// whole token units and scaled by the token's decimals with
+    /// `from_human` before being applied.
+    ///
+    /// # Returns
+    /// * `Err(Error::NotInitialized)` - The token decimals are not known
+    /// * `Err(Error::InvalidAmount)` - A bound overflows once scaled
+    pub fn update_config_limits_human(
+        env: Env,
+        limits: ConfigLimits,
+    ) -> Result<Option<u64>, Error> {
+        let mut scaled = limits.clone();
+        if let Some(min) = limits.min_bounty_amount {
+            scaled.min_bounty_amount = Some(Self::from_human(env.clone(), min)?);
+        }
+        if let Some(max) = limits.max_bounty_amount {
+            scaled.max_bounty_amount = Some(Self::from_human(env.clone(), max)?);
+        }
+        Self::update_config_limits(env, scaled)
+    }
 
-        emit_contract_unpaused(
-            &env,
-            ContractUnpaused {
-                unpaused_by: admin.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
+    /// Records the escrow token's decimals (admin only). Needed for tokens
+    /// that did not report them at `init`.
+    pub fn set_token_decimals(env: Env, decimals: u32) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        if 10i128.checked_pow(decimals).is_none() {
+            return Err(Error::InvalidAmount);
+        }
 
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenDecimals, &decimals);
         Ok(())
     }
 
-    /// Emergency withdrawal for all contract funds (admin only, only when paused)
-    /// This function allows admins to recover all contract funds in case of critical
-    /// security issues or unrecoverable bugs. It can only be called when the contract
-    /// is paused to prevent misuse.
-    pub fn emergency_withdraw(env: Env, recipient: Address) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    /// Decimals of the escrow token, if known (view function)
+    pub fn get_token_decimals(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::TokenDecimals)
+    }
 
-        // Only allow emergency withdrawal when contract is paused
-        if !Self::is_paused_internal(&env) {
-            return Err(Error::Unauthorized);
-        }
+    /// Converts a raw token amount into whole units, rounding down
+    /// (view function).
+    pub fn to_human(env: Env, amount: i128) -> Result<i128, Error> {
+        Ok(amount / Self::token_unit(&env)?)
+    }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+    /// Converts whole token units into a raw token amount (view function).
+    ///
+    /// # Returns
+    /// * `Err(Error::InvalidAmount)` - The result does not fit in an `i128`
+    pub fn from_human(env: Env, whole_units: i128) -> Result<i128, Error> {
+        whole_units
+            .checked_mul(Self::token_unit(&env)?)
+            .ok_or(Error::InvalidAmount)
+    }
 
-        // Get contract balance
-        let balance = client.balance(&env.current_contract_address());
+    /// Raw amount of one whole token, `10^decimals`.
+    fn token_unit(env: &Env) -> Result<i128, Error> {
+        let decimals = Self::get_token_decimals(env.clone()).ok_or(Error::NotInitialized)?;
+        10i128.checked_pow(decimals).ok_or(Error::InvalidAmount)
+    }
 
-        if balance <= 0 {
-            return Ok(()); // No funds to withdraw
+    /// Hands fee and config-limit changes to a governance contract, so they
+    /// only happen through a voted proposal's `execute_proposal`.
+    ///
+    /// The first call is admin only and goes through the time-lock queue.
+    /// After that only the current governance address can replace itself.
+    pub fn set_governance(env: Env, governance_address: Address) -> Result<Option<u64>, Error> {
+        let action_type = AdminActionType::Governance(governance_address);
+        match Self::get_governance(env.clone()) {
+            Some(governance) => {
+                governance.require_auth();
+                Self::apply_admin_action(&env, &action_type);
+                Ok(None)
+            }
+            None => {
+                let admin = Self::require_admin(&env)?;
+                Ok(Self::submit_admin_action(&env, admin, action_type))
+            }
         }
+    }
 
-        // Transfer all funds to recipient
-        client.transfer(&env.current_contract_address(), &recipient, &balance);
+    /// Get the governance address, if governance mode is on (view function)
+    pub fn get_governance(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Governance)
+    }
 
-        emit_emergency_withdrawal(
+    /// Sets how long admin config changes wait before they can be executed
+    /// (admin only). Changing it is itself subject to the current time-lock.
+    pub fn set_time_lock_duration(env: Env, duration: u64) -> Result<Option<u64>, Error> {
+        let admin = Self::require_admin(&env)?;
+        Ok(Self::submit_admin_action(
             &env,
-            EmergencyWithdrawal {
-                withdrawn_by: admin.clone(),
-                amount: balance,
-                recipient: recipient.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
+            admin,
+            AdminActionType::TimeLock(duration),
+        ))
+    }
 
-        Ok(())
+    /// Registers the ed25519 public key whose signatures `release_with_signature`
+    /// accepts (admin only). Goes through the time-lock queue.
+    pub fn update_signing_key(env: Env, public_key: BytesN<32>) -> Result<Option<u64>, Error> {
+        let admin = Self::require_admin(&env)?;
+        Ok(Self::submit_admin_action(
+            &env,
+            admin,
+            AdminActionType::SigningKey(public_key),
+        ))
     }
 
-    /// Lock funds for a specific bounty.
+    /// Requires `threshold` distinct approvals from `signers`, see
+    /// `approve_release`, before any payout from escrow: releases, batch
+    /// releases, milestones, settlements, unscheduled remainders, residuals
+    /// and new release schedules (admin only). An empty signer list turns
+    /// multisig off. Goes through the time-lock queue.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `depositor` - Address depositing the funds (must authorize)
-    /// * `bounty_id` - Unique identifier for this bounty
-    /// * `amount` - Token amount to lock (in smallest denomination)
-    /// * `deadline` - Unix timestamp after which refund is allowed
+    /// # Returns
+    /// * `Err(Error::InvalidThreshold)` - Threshold is zero or above the signer
+    ///   count, or a signer is listed twice
+    pub fn set_admin_signers(
+        env: Env,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<Option<u64>, Error> {
+        let admin = Self::require_admin(&env)?;
+
+        if signers.is_empty() {
+            if threshold != 0 {
+                return Err(Error::InvalidThreshold);
+            }
+        } else if threshold == 0 || threshold > signers.len() {
+            return Err(Error::InvalidThreshold);
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            if signers.iter().skip(i + 1).any(|other| other == signer) {
+                return Err(Error::InvalidThreshold);
+            }
+        }
+
+        Ok(Self::submit_admin_action(
+            &env,
+            admin,
+            AdminActionType::Signers(MultisigConfig { signers, threshold }),
+        ))
+    }
+
+    /// Get the multisig signer set, if configured (view function)
+    pub fn get_admin_signers(env: Env) -> Option<MultisigConfig> {
+        env.storage().instance().get(&DataKey::MultisigConfig)
+    }
+
+    /// Records a signer's approval to pay `amount` of a bounty to
+    /// `recipient`. The payout must match both exactly. Approving different
+    /// terms discards the approvals collected for the previous ones.
+    ///
+    /// # Returns
+    /// * `Err(Error::Unauthorized)` - Multisig is off or `signer` isn't in the set
+    /// * `Err(Error::AlreadyApproved)` - `signer` already approved these terms
+    pub fn approve_release(
+        env: Env,
+        bounty_id: u64,
+        signer: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        signer.require_auth();
+
+        let config: MultisigConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::MultisigConfig)
+            .ok_or(Error::Unauthorized)?;
+        if !config.signers.contains(&signer) {
+            return Err(Error::Unauthorized);
+        }
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut approval = match Self::get_release_approvals(env.clone(), bounty_id) {
+            Some(approval) if approval.recipient == recipient && approval.amount == amount => {
+                approval
+            }
+            _ => ReleaseApproval {
+                recipient,
+                amount,
+                signers: Vec::new(&env),
+            },
+        };
+        if approval.signers.contains(&signer) {
+            return Err(Error::AlreadyApproved);
+        }
+        approval.signers.push_back(signer);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseApprovals(bounty_id), &approval);
+
+        Ok(())
+    }
+
+    /// Get the approvals collected for a bounty's next payout (view function)
+    pub fn get_release_approvals(env: Env, bounty_id: u64) -> Option<ReleaseApproval> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseApprovals(bounty_id))
+    }
+
+    /// With multisig on, checks that enough signers approved paying `amount`
+    /// to `recipient` and uses up the approval (internal helper)
+    fn consume_release_approval(
+        env: &Env,
+        bounty_id: u64,
+        recipient: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let config = match Self::get_admin_signers(env.clone()) {
+            Some(config) => config,
+            None => return Ok(()),
+        };
+        let approval = Self::get_release_approvals(env.clone(), bounty_id)
+            .filter(|approval| approval.recipient == *recipient && approval.amount == amount)
+            .ok_or(Error::InsufficientApprovals)?;
+        let approved = approval
+            .signers
+            .iter()
+            .filter(|signer| config.signers.contains(signer))
+            .count() as u32;
+        if approved < config.threshold {
+            return Err(Error::InsufficientApprovals);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReleaseApprovals(bounty_id));
+        Ok(())
+    }
+
+    /// Turns the contributor acknowledgement requirement on or off (admin
+    /// only). While on, releases, batch releases, milestones, settlements,
+    /// unscheduled remainders and new release schedules only pay a
+    /// contributor who has called `contributor_acknowledge` for that bounty.
+    /// Off by default.
+    pub fn set_require_acknowledgement(env: Env, required: bool) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RequireAcknowledgement, &required);
+        Ok(())
+    }
+
+    /// Whether releases need the contributor's acknowledgement (view function)
+    pub fn is_acknowledgement_required(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::RequireAcknowledgement)
+            .unwrap_or(false)
+    }
+
+    /// Records that `contributor` accepts the bounty's terms and payout
+    /// address. When the depositor designated contributors through
+    /// `set_allowed_recipients`, only they can acknowledge; without an
+    /// allow-list anyone can. An acknowledgement can only be replaced once
+    /// its contributor is no longer allowed.
+    ///
+    /// # Returns
+    /// * `Err(Error::BountyNotFound)` - No escrow with this id
+    /// * `Err(Error::Unauthorized)` - `contributor` isn't on the bounty's allow-list
+    /// * `Err(Error::AlreadyAcknowledged)` - Another designated contributor acknowledged first
+    pub fn contributor_acknowledge(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        contributor.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        Self::ensure_allowed_recipient(&env, bounty_id, &contributor)?;
+        if let Some(existing) = Self::get_acknowledgement(env.clone(), bounty_id) {
+            if existing != contributor
+                && Self::ensure_allowed_recipient(&env, bounty_id, &existing).is_ok()
+            {
+                return Err(Error::AlreadyAcknowledged);
+            }
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Acknowledgement(bounty_id), &contributor);
+        Ok(())
+    }
+
+    /// Get the contributor who acknowledged a bounty, if any (view function)
+    pub fn get_acknowledgement(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Acknowledgement(bounty_id))
+    }
+
+    /// Lets the designated contributor turn a bounty down, refunding the
+    /// full remaining amount to the depositor without waiting for the
+    /// deadline.
+    ///
+    /// # Arguments
+    /// * `contributor` - A contributor the depositor designated in the
+    ///   bounty's allowed recipients
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - Net amount refunded to the depositor
+    /// * `Err(Error::Unauthorized)` - `contributor` isn't designated for the bounty
+    /// * `Err(Error::ContractPaused)` - Refunds are paused
+    /// * `Err(Error::FundsNotLocked)` - Nothing is left in escrow
+    /// * `Err(Error::BountyDisputed)` - The bounty is under dispute
+    /// * `Err(Error::SchedulesPending)` - Release schedules are still pending
+    pub fn decline_bounty(env: Env, bounty_id: u64, contributor: Address) -> Result<i128, Error> {
+        contributor.require_auth();
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if !Self::is_designated_contributor(&env, bounty_id, &contributor) {
+            return Err(Error::Unauthorized);
+        }
+        if Self::is_scope_paused(&env, PauseScope::Refund) {
+            return Err(Error::ContractPaused);
+        }
+
+        if escrow.status != EscrowStatus::Locked
+            && escrow.status != EscrowStatus::PartiallyReleased
+            && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.disputed {
+            return Err(Error::BountyDisputed);
+        }
+        if !Self::get_pending_schedules(env.clone(), bounty_id).is_empty() {
+            return Err(Error::SchedulesPending);
+        }
+
+        Self::enter_guard(&env, symbol_short!("decline"));
+
+        let refund_amount = escrow.remaining_amount;
+        let depositor = escrow.depositor.clone();
+        let net_amount = Self::execute_refund(
+            &env,
+            bounty_id,
+            escrow,
+            refund_amount,
+            depositor,
+            RefundMode::Full,
+            contributor.clone(),
+            None,
+        )?;
+
+        Self::exit_guard(&env, symbol_short!("decline"));
+
+        events::emit_bounty_declined(
+            &env,
+            events::BountyDeclined {
+                bounty_id,
+                contributor,
+                refunded_amount: net_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(net_amount)
+    }
+
+    /// Flags a bounty as disputed, freezing its releases and refunds until
+    /// the admin calls `resolve_dispute`.
+    ///
+    /// # Arguments
+    /// * `caller` - The depositor, or a contributor designated in the bounty's
+    ///   allowed recipients
+    /// * `reason` - Free-form description of the dispute, emitted in the event
+    ///
+    /// # Returns
+    /// * `Err(Error::Unauthorized)` - `caller` is neither depositor nor contributor
+    /// * `Err(Error::FundsNotLocked)` - Nothing is left in escrow to freeze
+    /// * `Err(Error::BountyDisputed)` - A dispute is already open
+    pub fn raise_dispute(
+        env: Env,
+        bounty_id: u64,
+        caller: Address,
+        reason: String,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if caller != escrow.depositor && !Self::is_designated_contributor(&env, bounty_id, &caller)
+        {
+            return Err(Error::Unauthorized);
+        }
+        if escrow.remaining_amount <= 0 {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.disputed {
+            return Err(Error::BountyDisputed);
+        }
+
+        escrow.disputed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        emit_dispute_raised(
+            &env,
+            DisputeRaised {
+                bounty_id,
+                raised_by: caller,
+                reason,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Closes a bounty's dispute so releases and refunds can go ahead
+    /// (admin only). `outcome` records the decision in the event.
+    ///
+    /// # Returns
+    /// * `Err(Error::NoActiveDispute)` - The bounty isn't disputed
+    pub fn resolve_dispute(env: Env, bounty_id: u64, outcome: String) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.disputed {
+            return Err(Error::NoActiveDispute);
+        }
+
+        escrow.disputed = false;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        emit_dispute_resolved(
+            &env,
+            DisputeResolved {
+                bounty_id,
+                resolved_by: admin,
+                outcome,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Applies a queued admin action once its time-lock has expired (admin only).
+    ///
+    /// # Returns
+    /// * `Err(Error::ActionNotFound)` - No action with this id
+    /// * `Err(Error::ActionAlreadyExecuted)` - Already executed or cancelled
+    /// * `Err(Error::ActionNotReady)` - `execute_after` hasn't been reached
+    /// * `Err(Error::ActionExpired)` - `expires_at` has passed; propose it again
+    pub fn execute_admin_action(env: Env, action_id: u64) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+
+        let mut action = Self::get_admin_action(env.clone(), action_id)?;
+        if action.executed {
+            return Err(Error::ActionAlreadyExecuted);
+        }
+        let now = env.ledger().timestamp();
+        if now < action.execute_after {
+            return Err(Error::ActionNotReady);
+        }
+        if now > action.expires_at {
+            return Err(Error::ActionExpired);
+        }
+
+        action.executed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::AdminAction(action_id), &action);
+        Self::remove_pending_action(&env, action_id);
+
+        Self::apply_admin_action(&env, &action.action_type);
+
+        events::emit_admin_action_executed(
+            &env,
+            events::AdminActionExecuted {
+                action_id,
+                action_type: action.action_type,
+                executed_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Drops a queued admin action without applying it (admin only).
+    pub fn cancel_admin_action(env: Env, action_id: u64) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+
+        let mut action = Self::get_admin_action(env.clone(), action_id)?;
+        if action.executed {
+            return Err(Error::ActionAlreadyExecuted);
+        }
+
+        // Marked executed so it can never be applied later
+        action.executed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::AdminAction(action_id), &action);
+        Self::remove_pending_action(&env, action_id);
+
+        events::emit_admin_action_cancelled(
+            &env,
+            events::AdminActionCancelled {
+                action_id,
+                cancelled_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Retrieves a queued admin action.
+    pub fn get_admin_action(env: Env, action_id: u64) -> Result<AdminAction, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AdminAction(action_id))
+            .ok_or(Error::ActionNotFound)
+    }
+
+    /// All queued admin actions that haven't been executed or cancelled, in
+    /// proposal order. Expired actions stay listed until cancelled.
+    pub fn get_pending_admin_actions(env: Env) -> Vec<AdminAction> {
+        let mut actions = Vec::new(&env);
+        for action_id in Self::pending_action_ids(&env).iter() {
+            if let Ok(action) = Self::get_admin_action(env.clone(), action_id) {
+                actions.push_back(action);
+            }
+        }
+        actions
+    }
+
+    /// Get the admin action time-lock in seconds (view function)
+    pub fn get_time_lock_duration(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TimeLockDuration)
+            .unwrap_or(0)
+    }
+
+    /// Sets how long a queued admin action stays executable once its
+    /// time-lock has passed (admin only). Applies to actions proposed after
+    /// the change.
+    pub fn set_admin_action_expiry(env: Env, window: u64) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminActionExpiry, &window);
+        Ok(())
+    }
+
+    /// Seconds a ready admin action stays executable (seven days unless set)
+    pub fn get_admin_action_expiry(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AdminActionExpiry)
+            .unwrap_or(DEFAULT_ADMIN_ACTION_EXPIRY)
+    }
+
+    /// Sets how many items a single batch call may process (admin only).
+    ///
+    /// # Returns
+    /// * `Err(Error::InvalidBatchSize)` - Zero or above `MAX_BATCH_SIZE_CAP`
+    pub fn set_max_batch_size(env: Env, max_batch_size: u32) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        if max_batch_size == 0 || max_batch_size > MAX_BATCH_SIZE_CAP {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let old_size = Self::max_batch_size(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxBatchSize, &max_batch_size);
+        emit_max_batch_size_updated(
+            &env,
+            MaxBatchSizeUpdated {
+                old_size,
+                new_size: max_batch_size,
+                updated_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Item limit for batch operations (100 unless set) (view function)
+    pub fn get_max_batch_size(env: Env) -> u32 {
+        Self::max_batch_size(&env)
+    }
+
+    fn max_batch_size(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxBatchSize)
+            .unwrap_or(DEFAULT_MAX_BATCH_SIZE)
+    }
+
+    /// True if the action is still pending but can no longer be executed
+    /// because its expiry has passed. False for unknown ids.
+    pub fn is_action_expired(env: Env, action_id: u64) -> bool {
+        match Self::get_admin_action(env.clone(), action_id) {
+            Ok(action) => !action.executed && env.ledger().timestamp() > action.expires_at,
+            Err(_) => false,
+        }
+    }
+
+    /// Restricts who the bounty's funds can be released to (depositor only).
+    /// Releases, schedules and settlements naming any other recipient fail
+    /// with `Unauthorized`, which limits what a leaked admin or payout key
+    /// can do. An empty list lifts the restriction.
+    ///
+    /// # Returns
+    /// * `Err(Error::InvalidBatchSize)` - More than `get_max_batch_size` recipients
+    pub fn set_allowed_recipients(
+        env: Env,
+        bounty_id: u64,
+        recipients: Vec<Address>,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+
+        if recipients.len() > Self::max_batch_size(&env) {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let key = DataKey::AllowedRecipients(bounty_id);
+        if recipients.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &recipients);
+        }
+        Ok(())
+    }
+
+    /// Get the recipients a bounty may be released to; empty means any
+    /// (view function)
+    pub fn get_allowed_recipients(env: Env, bounty_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AllowedRecipients(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// True if the depositor named `contributor` in the bounty's allowed
+    /// recipients. An empty list designates nobody (internal helper)
+    fn is_designated_contributor(env: &Env, bounty_id: u64, contributor: &Address) -> bool {
+        Self::get_allowed_recipients(env.clone(), bounty_id).contains(contributor)
+    }
+
+    /// Rejects `contributor` while acknowledgement is required and they
+    /// aren't the one who acknowledged the bounty (internal helper)
+    fn ensure_acknowledged(env: &Env, bounty_id: u64, contributor: &Address) -> Result<(), Error> {
+        if Self::is_acknowledgement_required(env.clone())
+            && Self::get_acknowledgement(env.clone(), bounty_id).as_ref() != Some(contributor)
+        {
+            return Err(Error::ContributorNotAcknowledged);
+        }
+        Ok(())
+    }
+
+    /// Rejects `recipient` when the bounty has an allow-list that doesn't
+    /// include it (internal helper)
+    fn ensure_allowed_recipient(
+        env: &Env,
+        bounty_id: u64,
+        recipient: &Address,
+    ) -> Result<(), Error> {
+        let allowed = Self::get_allowed_recipients(env.clone(), bounty_id);
+        if !allowed.is_empty() && !allowed.contains(recipient) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Get the admin address set at `init` (view function)
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Get the default token set at `init` (view function)
+    pub fn get_token(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Get the configured payout key, if any (view function)
+    pub fn get_payout_key(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PayoutKey)
+    }
+
+    /// Get the limits applied to new locks (view function)
+    pub fn get_config_limits(env: Env) -> ConfigLimits {
+        env.storage()
+            .instance()
+            .get(&DataKey::ConfigLimits)
+            .unwrap_or(ConfigLimits {
+                min_bounty_amount: None,
+                max_bounty_amount: None,
+                min_deadline_duration: None,
+                max_deadline_duration: None,
+            })
+    }
+
+    /// Marks the contract as inside a token-transferring `operation` and
+    /// rejects the same operation re-entering itself (internal helper)
+    ///
+    /// Each operation has its own flag, so e.g. a refund reached from inside
+    /// a lock isn't refused; only a nested lock is.
+    ///
+    /// The guard can't outlive a failed call: a panic or `Err` return rolls
+    /// back every storage write of the invocation, including this one.
+    /// Temporary storage wouldn't be safer, since it lives until its TTL
+    /// runs out rather than for one transaction.
+    fn enter_guard(env: &Env, operation: Symbol) {
+        let key = DataKey::ReentrancyGuard(operation);
+        if env.storage().instance().has(&key) {
+            panic!("Reentrancy detected");
+        }
+        env.storage().instance().set(&key, &true);
+    }
+
+    /// Clears the guard set by `enter_guard` for `operation` (internal helper)
+    fn exit_guard(env: &Env, operation: Symbol) {
+        env.storage()
+            .instance()
+            .remove(&DataKey::ReentrancyGuard(operation));
+    }
+
+    /// Loads the admin and requires its authorization (internal helper)
+    fn require_admin(env: &Env) -> Result<Address, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Ok(admin)
+    }
+
+    /// Applies the change right away when there is no time-lock, otherwise
+    /// queues it. Returns the action id when queued.
+    fn submit_admin_action(env: &Env, admin: Address, action_type: AdminActionType) -> Option<u64> {
+        let time_lock = Self::get_time_lock_duration(env.clone());
+        if time_lock == 0 {
+            Self::apply_admin_action(env, &action_type);
+            return None;
+        }
+
+        let action_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextActionId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextActionId, &(action_id + 1));
+
+        let now = env.ledger().timestamp();
+        let execute_after = now + time_lock;
+        let action = AdminAction {
+            action_id,
+            action_type: action_type.clone(),
+            proposed_by: admin.clone(),
+            proposed_at: now,
+            execute_after,
+            expires_at: execute_after + Self::get_admin_action_expiry(env.clone()),
+            executed: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::AdminAction(action_id), &action);
+        let mut pending = Self::pending_action_ids(env);
+        pending.push_back(action_id);
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingActionIds, &pending);
+
+        events::emit_admin_action_proposed(
+            env,
+            events::AdminActionProposed {
+                action_id,
+                action_type,
+                proposed_by: admin,
+                execute_after: action.execute_after,
+            },
+        );
+
+        Some(action_id)
+    }
+
+    fn pending_action_ids(env: &Env) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PendingActionIds)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn remove_pending_action(env: &Env, action_id: u64) {
+        let mut pending = Self::pending_action_ids(env);
+        if let Some(index) = pending.first_index_of(action_id) {
+            pending.remove(index);
+            env.storage()
+                .instance()
+                .set(&DataKey::PendingActionIds, &pending);
+        }
+    }
+
+    /// Swaps the contract and anti-abuse admin (internal helper)
+    fn set_admin_internal(env: &Env, new_admin: Address) {
+        let old_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        anti_abuse::set_admin(env, new_admin.clone());
+        events::emit_admin_updated(
+            env,
+            events::AdminUpdated {
+                old_admin,
+                new_admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Writes an admin config change to storage (internal helper)
+    fn apply_admin_action(env: &Env, action_type: &AdminActionType) {
+        match action_type {
+            AdminActionType::Admin(new_admin) => {
+                Self::set_admin_internal(env, new_admin.clone());
+            }
+            AdminActionType::PayoutKey(payout_key) => {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::PayoutKey, payout_key);
+            }
+            AdminActionType::ConfigLimits(limits) => {
+                env.storage().instance().set(&DataKey::ConfigLimits, limits);
+            }
+            AdminActionType::SigningKey(public_key) => {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::SigningKey, public_key);
+            }
+            AdminActionType::Signers(config) => {
+                if config.signers.is_empty() {
+                    env.storage().instance().remove(&DataKey::MultisigConfig);
+                } else {
+                    env.storage()
+                        .instance()
+                        .set(&DataKey::MultisigConfig, config);
+                }
+            }
+            AdminActionType::Governance(governance) => {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::Governance, governance);
+            }
+            AdminActionType::PendingAdmin(pending_admin) => {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::PendingAdmin, pending_admin);
+                events::emit_admin_proposed(
+                    env,
+                    events::AdminProposed {
+                        current_admin: env.storage().instance().get(&DataKey::Admin).unwrap(),
+                        pending_admin: pending_admin.clone(),
+                        timestamp: env.ledger().timestamp(),
+                    },
+                );
+            }
+            AdminActionType::ForceCloseDelay(delay) => {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::ForceCloseDelay, delay);
+                events::emit_force_close_delay_updated(
+                    env,
+                    events::ForceCloseDelayUpdated {
+                        delay: *delay,
+                        timestamp: env.ledger().timestamp(),
+                    },
+                );
+            }
+            AdminActionType::TimeLock(duration) => {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::TimeLockDuration, duration);
+            }
+            AdminActionType::FeeConfig(update) => {
+                let mut fee_config = Self::get_fee_config_internal(env);
+                if let Some(rate) = update.lock_fee_rate {
+                    fee_config.lock_fee_rate = rate;
+                }
+                if let Some(rate) = update.release_fee_rate {
+                    fee_config.release_fee_rate = rate;
+                }
+                if let Some(rate) = update.refund_fee_rate {
+                    fee_config.refund_fee_rate = rate;
+                }
+                if let Some(recipient) = update.fee_recipient.clone() {
+                    fee_config.fee_recipient = recipient;
+                }
+                if let Some(enabled) = update.fee_enabled {
+                    fee_config.fee_enabled = enabled;
+                }
+
+                env.storage()
+                    .instance()
+                    .set(&DataKey::FeeConfig, &fee_config);
+
+                events::emit_fee_config_updated(
+                    env,
+                    events::FeeConfigUpdated {
+                        lock_fee_rate: fee_config.lock_fee_rate,
+                        release_fee_rate: fee_config.release_fee_rate,
+                        refund_fee_rate: fee_config.refund_fee_rate,
+                        fee_recipient: fee_config.fee_recipient.clone(),
+                        fee_enabled: fee_config.fee_enabled,
+                        timestamp: env.ledger().timestamp(),
+                    },
+                );
+            }
+        }
+    }
+
+    // ========================================================================
+    // Pause and Emergency Functions
+    // ========================================================================
+
+    /// Check if contract is paused (internal helper)
+    fn is_paused_internal(env: &Env) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, bool>(&DataKey::IsPaused)
+            .unwrap_or(false)
+    }
+
+    /// Get pause status (view function)
+    pub fn is_paused(env: Env) -> bool {
+        Self::is_paused_internal(&env)
+    }
+
+    fn paused_scopes_internal(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PausedScopes)
+            .unwrap_or(0)
+    }
+
+    /// True if `scope` is paused on its own or the whole contract is paused
+    fn is_scope_paused(env: &Env, scope: PauseScope) -> bool {
+        Self::is_paused_internal(env)
+            || Self::paused_scopes_internal(env) & (1 << scope as u32) != 0
+    }
+
+    /// Whether operations in `scope` are currently blocked (view function)
+    pub fn is_operation_paused(env: Env, scope: PauseScope) -> bool {
+        Self::is_scope_paused(&env, scope)
+    }
+
+    /// Pause only the given operation scopes (admin or `Pauser`).
+    /// Operations outside `scopes` keep working, e.g. pausing `Lock` stops new
+    /// deposits while refunds and releases still go through.
+    pub fn pause_operations(
+        env: Env,
+        caller: Address,
+        scopes: Vec<PauseScope>,
+    ) -> Result<(), Error> {
+        Self::require_role(&env, roles::Role::Pauser, &caller)?;
+
+        let mut mask = Self::paused_scopes_internal(&env);
+        for scope in scopes.iter() {
+            mask |= 1 << scope as u32;
+        }
+        Self::set_paused_scopes(&env, caller, mask);
+        Ok(())
+    }
+
+    /// Resume the given operation scopes (admin or `Pauser`).
+    /// A full `pause` still blocks every scope until `unpause` is called.
+    pub fn unpause_operations(
+        env: Env,
+        caller: Address,
+        scopes: Vec<PauseScope>,
+    ) -> Result<(), Error> {
+        Self::require_role(&env, roles::Role::Pauser, &caller)?;
+
+        let mut mask = Self::paused_scopes_internal(&env);
+        for scope in scopes.iter() {
+            mask &= !(1 << scope as u32);
+        }
+        Self::set_paused_scopes(&env, caller, mask);
+        Ok(())
+    }
+
+    fn set_paused_scopes(env: &Env, caller: Address, mask: u32) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PausedScopes, &mask);
+        emit_pause_scopes_updated(
+            env,
+            PauseScopesUpdated {
+                paused_scopes: mask,
+                updated_by: caller,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Pause the contract (admin or `Pauser`)
+    /// Prevents new fund locks, releases, and refunds
+    pub fn pause(env: Env, caller: Address) -> Result<(), Error> {
+        Self::require_role(&env, roles::Role::Pauser, &caller)?;
+
+        if Self::is_paused_internal(&env) {
+            return Ok(()); // Already paused, idempotent
+        }
+
+        env.storage().persistent().set(&DataKey::IsPaused, &true);
+
+        emit_contract_paused(
+            &env,
+            ContractPaused {
+                paused_by: caller,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Unpause the contract (admin or `Pauser`)
+    /// Resumes normal operations, including any individually paused scopes
+    pub fn unpause(env: Env, caller: Address) -> Result<(), Error> {
+        Self::require_role(&env, roles::Role::Pauser, &caller)?;
+
+        if !Self::is_paused_internal(&env) {
+            return Ok(()); // Already unpaused, idempotent
+        }
+
+        env.storage().persistent().set(&DataKey::IsPaused, &false);
+        env.storage().persistent().remove(&DataKey::PausedScopes);
+
+        emit_contract_unpaused(
+            &env,
+            ContractUnpaused {
+                unpaused_by: caller,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Emergency withdrawal for all contract funds (admin only, only when paused)
+    /// This function allows admins to recover all contract funds in case of critical
+    /// security issues or unrecoverable bugs. It can only be called when the contract
+    /// is paused to prevent misuse. Escrow records are left as they were; use
+    /// `emergency_withdraw_bounties` to drain specific escrows and keep their
+    /// state in sync. Only the contract's default token is drained; bounties
+    /// locked in other tokens need `emergency_withdraw_bounties`.
+    pub fn emergency_withdraw(env: Env, recipient: Address) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        // Only allow emergency withdrawal when contract is paused
+        if !Self::is_paused_internal(&env) {
+            return Err(Error::Unauthorized);
+        }
+
+        if recipient == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+
+        // Get contract balance
+        let balance = client.balance(&env.current_contract_address());
+
+        if balance <= 0 {
+            return Ok(()); // No funds to withdraw
+        }
+
+        // Transfer all funds to recipient
+        client.transfer(&env.current_contract_address(), &recipient, &balance);
+
+        emit_emergency_withdrawal(
+            &env,
+            EmergencyWithdrawal {
+                withdrawn_by: admin.clone(),
+                amount: balance,
+                recipient: recipient.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Emergency withdrawal of specific escrows (admin only, only when paused).
+    ///
+    /// Unlike `emergency_withdraw`, which drains the whole token balance and
+    /// leaves escrow records untouched, this transfers each listed bounty's
+    /// `remaining_amount` to `recipient`, zeroes it and marks the escrow
+    /// `EmergencyWithdrawn`, so views stay consistent with the actual balance.
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - Total amount withdrawn across all listed bounties
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Contract is not paused
+    /// * `InvalidBatchSize` - `bounty_ids` is empty or exceeds `get_max_batch_size`
+    /// * `DuplicateBountyId` - A bounty id is listed more than once
+    /// * `BountyNotFound` - A listed bounty doesn't exist
+    /// * `FundsNotLocked` - A listed bounty has no remaining funds
+    ///
+    /// # Events
+    /// Emits `EmergencyWithdrawal` with topics `("ewith", bounty_id)` per escrow.
+    pub fn emergency_withdraw_bounties(
+        env: Env,
+        recipient: Address,
+        bounty_ids: Vec<u64>,
+    ) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !Self::is_paused_internal(&env) {
+            return Err(Error::Unauthorized);
+        }
+
+        if recipient == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+
+        if bounty_ids.is_empty() || bounty_ids.len() > Self::max_batch_size(&env) {
+            return Err(Error::InvalidBatchSize);
+        }
+        Self::ensure_unique_bounty_ids(&env, bounty_ids.iter())?;
+
+        let timestamp = env.ledger().timestamp();
+
+        let mut total: i128 = 0;
+        for bounty_id in bounty_ids.iter() {
+            let mut escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(bounty_id))
+                .ok_or(Error::BountyNotFound)?;
+            if escrow.remaining_amount <= 0 {
+                return Err(Error::FundsNotLocked);
+            }
+
+            let amount = escrow.remaining_amount;
+            token::Client::new(&env, &escrow.token).transfer(
+                &env.current_contract_address(),
+                &recipient,
+                &amount,
+            );
+
+            escrow.remaining_amount = 0;
+            escrow.status = EscrowStatus::EmergencyWithdrawn;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+
+            emit_escrow_emergency_withdrawal(
+                &env,
+                bounty_id,
+                EmergencyWithdrawal {
+                    withdrawn_by: admin.clone(),
+                    amount,
+                    recipient: recipient.clone(),
+                    timestamp,
+                },
+            );
+            Self::emit_lifecycle(
+                &env,
+                bounty_id,
+                symbol_short!("emergency"),
+                admin.clone(),
+                amount,
+            );
+            Self::record_status_change(&env, bounty_id, escrow.status, admin.clone());
+
+            total += amount;
+        }
+
+        Ok(total)
+    }
+
+    /// Sets how long after its deadline a bounty must sit before the admin
+    /// can `force_close` it, in seconds (admin only). Goes through the
+    /// time-lock queue.
+    ///
+    /// # Returns
+    /// * `Err(Error::ForceCloseDelayTooShort)` - `delay` is under 30 days
+    pub fn set_force_close_delay(env: Env, delay: u64) -> Result<Option<u64>, Error> {
+        let admin = Self::require_admin(&env)?;
+        if delay < MIN_FORCE_CLOSE_DELAY {
+            return Err(Error::ForceCloseDelayTooShort);
+        }
+
+        Ok(Self::submit_admin_action(
+            &env,
+            admin,
+            AdminActionType::ForceCloseDelay(delay),
+        ))
+    }
+
+    /// Seconds after the deadline before `force_close` is allowed (one year
+    /// unless set)
+    pub fn get_force_close_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ForceCloseDelay)
+            .unwrap_or(DEFAULT_FORCE_CLOSE_DELAY)
+    }
+
+    /// Sends what's left in an abandoned bounty to `treasury` and marks it
+    /// `ForceClosed` (admin only). Only allowed once the force-close delay
+    /// has passed since the deadline, so dust and forgotten escrows can be
+    /// recovered without touching live ones.
+    ///
+    /// Like any other payout, a treasury other than the depositor must be on
+    /// the bounty's allow-list, and the transfer needs multisig approval when
+    /// signers are configured.
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - Amount sent to the treasury
+    /// * `Err(Error::DeadlineNotPassed)` - The bounty isn't old enough yet
+    /// * `Err(Error::FundsNotLocked)` - Nothing is left in escrow
+    /// * `Err(Error::BountyDisputed)` - The bounty is under dispute
+    /// * `Err(Error::InsufficientApprovals)` - Signers haven't approved it
+    pub fn force_close(env: Env, bounty_id: u64, treasury: Address) -> Result<i128, Error> {
+        let admin = Self::require_admin(&env)?;
+
+        if treasury == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if (escrow.status != EscrowStatus::Locked
+            && escrow.status != EscrowStatus::PartiallyReleased
+            && escrow.status != EscrowStatus::PartiallyRefunded)
+            || escrow.remaining_amount <= 0
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.disputed {
+            return Err(Error::BountyDisputed);
+        }
+
+        let timestamp = env.ledger().timestamp();
+        let closable_at = escrow
+            .deadline
+            .saturating_add(Self::get_force_close_delay(env.clone()));
+        if timestamp < closable_at {
+            return Err(Error::DeadlineNotPassed);
+        }
+
+        let amount = escrow.remaining_amount;
+        if treasury != escrow.depositor {
+            Self::ensure_allowed_recipient(&env, bounty_id, &treasury)?;
+        }
+        Self::consume_release_approval(&env, bounty_id, &treasury, amount)?;
+
+        Self::enter_guard(&env, symbol_short!("f_close"));
+
+        token::Client::new(&env, &escrow.token).transfer(
+            &env.current_contract_address(),
+            &treasury,
+            &amount,
+        );
+
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::ForceClosed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        events::emit_force_closed(
+            &env,
+            events::ForceClosed {
+                bounty_id,
+                amount,
+                treasury,
+                closed_by: admin.clone(),
+                timestamp,
+            },
+        );
+        Self::emit_lifecycle(
+            &env,
+            bounty_id,
+            symbol_short!("f_close"),
+            admin.clone(),
+            amount,
+        );
+        Self::record_status_change(&env, bounty_id, escrow.status, admin);
+
+        Self::exit_guard(&env, symbol_short!("f_close"));
+
+        Ok(amount)
+    }
+
+    /// Lock funds for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock (in smallest denomination)
+    /// * `deadline` - Unix timestamp after which refund is allowed
     ///
     /// # Returns
     /// * `Ok(())` - Funds successfully locked
     /// * `Err(Error::NotInitialized)` - Contract not initialized
-    /// * `Err(Error::BountyExists)` - Bounty ID already in use
+    /// * `Err(Error::BountyExists)` - Bounty ID already in use
+    ///
+    /// # State Changes
+    /// - Transfers `amount` tokens from depositor to contract
+    /// - Creates Escrow record in persistent storage
+    /// - Emits FundsLocked event
+    ///
+    /// # Authorization
+    /// - Depositor must authorize the transaction
+    /// - Depositor must have sufficient token balance
+    /// - Depositor must have approved contract for token transfer
+    ///
+    /// # Security Considerations
+    /// - Bounty ID must be unique (prevents overwrites)
+    /// - Amount must be positive (enforced by token contract)
+    /// - Deadline should be reasonable (recommended: 7-90 days)
+    /// - Token transfer is atomic with state update
+    ///
+    /// # Events
+    /// Emits: `FundsLocked { bounty_id, amount, depositor, deadline }`
+    ///
+    /// # Example
+    /// ```rust
+    /// let depositor = Address::from_string("GDEPOSIT...");
+    /// let amount = 1000_0000000; // 1000 USDC
+    /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60); // 30 days
+    ///
+    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline)?;
+    /// // Funds are now locked and can be released or refunded
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Medium - Token transfer + storage write + event emission
+    ///
+    /// # Common Pitfalls
+    /// - Forgetting to approve token contract before calling
+    /// - Using a bounty ID that already exists
+    /// - Setting deadline in the past or too far in the future
+    pub fn lock_funds(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        Self::lock_funds_internal(env, depositor, bounty_id, amount, deadline, None, false)?;
+        Ok(())
+    }
+
+    /// Same as `lock_funds`, returning a `LockReceipt` with the net amount
+    /// (after any lock fee), deadline and lock time.
+    pub fn lock_funds_v2(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<LockReceipt, Error> {
+        Self::lock_funds_internal(env, depositor, bounty_id, amount, deadline, None, false)
+    }
+
+    /// Lock funds for a bounty in a token other than the contract's default.
+    ///
+    /// Behaves like `lock_funds`; the escrow records `token` and every later
+    /// release, refund and scheduled payout for the bounty uses it.
+    pub fn lock_funds_with_token(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        token: Address,
+    ) -> Result<(), Error> {
+        Self::lock_funds_internal(
+            env,
+            depositor,
+            bounty_id,
+            amount,
+            deadline,
+            Some(token),
+            false,
+        )?;
+        Ok(())
+    }
+
+    /// Lock funds like `lock_funds`, tagged with a client-chosen key so a
+    /// retried submission can't lock twice.
+    ///
+    /// The key is remembered for about a day (temporary storage), after
+    /// which it expires and may be reused.
+    ///
+    /// # Returns
+    /// * `Err(Error::DuplicateRequest)` - A lock with this key already succeeded
+    pub fn lock_funds_idempotent(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        idempotency_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        let key = DataKey::IdempotencyKey(idempotency_key);
+        if env.storage().temporary().has(&key) {
+            return Err(Error::DuplicateRequest);
+        }
+
+        Self::lock_funds_internal(
+            env.clone(),
+            depositor,
+            bounty_id,
+            amount,
+            deadline,
+            None,
+            false,
+        )?;
+
+        env.storage().temporary().set(&key, &bounty_id);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, IDEMPOTENCY_KEY_TTL, IDEMPOTENCY_KEY_TTL);
+        Ok(())
+    }
+
+    /// Checks a new lock against the admin-configured `ConfigLimits`.
+    /// `deadline` must already be in the future.
+    fn check_config_limits(env: &Env, amount: i128, deadline: u64) -> Result<(), Error> {
+        let limits = Self::get_config_limits(env.clone());
+        if limits.min_bounty_amount.is_some_and(|min| amount < min)
+            || limits.max_bounty_amount.is_some_and(|max| amount > max)
+        {
+            return Err(Error::InvalidAmount);
+        }
+        let duration = deadline - env.ledger().timestamp();
+        if limits
+            .min_deadline_duration
+            .is_some_and(|min| duration < min)
+            || limits
+                .max_deadline_duration
+                .is_some_and(|max| duration > max)
+        {
+            return Err(Error::InvalidDeadline);
+        }
+        Ok(())
+    }
+
+    fn lock_funds_internal(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        token: Option<Address>,
+        fee_exempt: bool,
+    ) -> Result<LockReceipt, Error> {
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, depositor.clone(), symbol_short!("lock"));
+
+        let start = env.ledger().timestamp();
+        let caller = depositor.clone();
+
+        // Check if contract is paused
+        if Self::is_scope_paused(&env, PauseScope::Lock) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::ContractPaused);
+        }
+
+        // Verify depositor authorization
+        depositor.require_auth();
+
+        // Guard against reentrant calls through the token
+        Self::enter_guard(&env, symbol_short!("lock"));
+
+        if amount <= 0 {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            Self::exit_guard(&env, symbol_short!("lock"));
+            return Err(Error::InvalidAmount);
+        }
+
+        if deadline <= env.ledger().timestamp() {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            Self::exit_guard(&env, symbol_short!("lock"));
+            return Err(Error::InvalidDeadline);
+        }
+
+        // Enforce admin-configured limits
+        if let Err(err) = Self::check_config_limits(&env, amount, deadline) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            Self::exit_guard(&env, symbol_short!("lock"));
+            return Err(err);
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            Self::exit_guard(&env, symbol_short!("lock"));
+            return Err(Error::NotInitialized);
+        }
+
+        // Prevent duplicate bounty IDs
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            Self::exit_guard(&env, symbol_short!("lock"));
+            return Err(Error::BountyExists);
+        }
+
+        // Get token contract and transfer funds
+        let token_addr: Address =
+            token.unwrap_or_else(|| env.storage().instance().get(&DataKey::Token).unwrap());
+        let client = token::Client::new(&env, &token_addr);
+
+        // Calculate and collect fee if enabled
+        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_amount = if !fee_exempt && fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
+            Self::calculate_fee(amount, fee_config.lock_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = amount - fee_amount;
+
+        // Transfer net amount from depositor to contract
+        client.transfer(&depositor, &env.current_contract_address(), &net_amount);
+
+        // Transfer fee to fee recipient if applicable
+        if fee_amount > 0 {
+            client.transfer(&depositor, &fee_config.fee_recipient, &fee_amount);
+            events::emit_fee_collected(
+                &env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Lock,
+                    amount: fee_amount,
+                    fee_rate: fee_config.lock_fee_rate,
+                    recipient: fee_config.fee_recipient.clone(),
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        // Create escrow record
+        let escrow = Escrow {
+            depositor: depositor.clone(),
+            amount: net_amount, // Store net amount (after fee)
+            status: EscrowStatus::Locked,
+            deadline,
+            refund_history: vec![&env],
+            payout_history: vec![&env],
+            remaining_amount: net_amount,
+            token: token_addr,
+            disputed: false,
+        };
+
+        // Store in persistent storage with extended TTL
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        // Update registry
+        let mut registry: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::BountyRegistry)
+            .unwrap_or(vec![&env]);
+        registry.push_back(bounty_id);
+        env.storage()
+            .instance()
+            .set(&DataKey::BountyRegistry, &registry);
+
+        // Emit event for off-chain indexing
+        emit_funds_locked(
+            &env,
+            FundsLocked {
+                bounty_id,
+                amount: net_amount, // Emit net amount (after fee)
+                depositor: depositor.clone(),
+                deadline,
+            },
+        );
+        Self::emit_lifecycle(
+            &env,
+            bounty_id,
+            symbol_short!("lock"),
+            depositor.clone(),
+            net_amount,
+        );
+        Self::record_status_change(&env, bounty_id, escrow.status, depositor.clone());
+
+        Self::exit_guard(&env, symbol_short!("lock"));
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("lock"), caller, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("lock"), duration);
+
+        Ok(LockReceipt {
+            bounty_id,
+            net_amount,
+            deadline,
+            locked_at: env.ledger().timestamp(),
+        })
+    }
+
+    /// Releases escrowed funds to a contributor.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - The admin or the configured payout key
+    /// * `bounty_id` - The bounty to release funds for
+    /// * `contributor` - Address to receive the funds
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds successfully released
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::Unauthorized)` - Caller is neither the admin nor the payout key
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
+    ///
+    /// # State Changes
+    /// - Transfers tokens from contract to contributor
+    /// - Updates escrow status to Released
+    /// - Emits FundsReleased event
+    ///
+    /// # Authorization
+    /// - **CRITICAL**: Only the admin or the payout key can call this function
+    /// - The payout key lets a hot backend release without holding the admin key
+    ///
+    /// # Security Considerations
+    /// - This is the most security-critical function
+    /// - Admin should verify task completion off-chain before calling
+    /// - Once released, funds cannot be retrieved
+    /// - Recipient address should be verified carefully
+    /// - Consider implementing multi-sig for admin
+    ///
+    /// # Events
+    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp, remaining_amount }`
+    ///
+    /// # Example
+    /// ```rust
+    /// // After verifying task completion off-chain:
+    /// let contributor = Address::from_string("GCONTRIB...");
+    ///
+    /// // Admin calls release
+    /// escrow_client.release_funds(&admin, &42, &contributor, &None)?;
+    /// // Funds transferred to contributor, escrow marked as Released
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Medium - Token transfer + storage update + event emission
+    ///
+    /// # Best Practices
+    /// 1. Verify contributor identity off-chain
+    /// 2. Confirm task completion before release
+    /// 3. Log release decisions in backend system
+    /// 4. Monitor release events for anomalies
+    /// 5. Consider implementing release delays for high-value bounties
+    pub fn release_funds(
+        env: Env,
+        caller: Address,
+        bounty_id: u64,
+        contributor: Address,
+        amount: Option<i128>, // Optional partial amount
+    ) -> Result<(), Error> {
+        Self::release_funds_internal(env, bounty_id, contributor, amount, Some(caller), None)
+    }
+
+    /// Same as `release_funds`, recording `memo` (e.g. why the payout was
+    /// approved) in the payout history and the `FundsReleased` event.
+    pub fn release_funds_with_memo(
+        env: Env,
+        caller: Address,
+        bounty_id: u64,
+        contributor: Address,
+        amount: Option<i128>,
+        memo: String,
+    ) -> Result<(), Error> {
+        Self::release_funds_internal(
+            env,
+            bounty_id,
+            contributor,
+            amount,
+            Some(caller),
+            Some(memo),
+        )
+    }
+
+    /// Returns whether `caller` may trigger releases: the admin, the
+    /// configured payout key, or a `Releaser`.
+    pub fn is_authorized_to_release(env: Env, caller: Address) -> bool {
+        let payout_key: Option<Address> = env.storage().instance().get(&DataKey::PayoutKey);
+        payout_key == Some(caller.clone()) || Self::has_role(env, roles::Role::Releaser, caller)
+    }
+
+    /// Releases funds to a contributor using an admin-signed authorization
+    /// that anyone (e.g. a relayer) can submit.
+    ///
+    /// The admin signs the XDR encoding of
+    /// `(network_id, contract, bounty_id, contributor, nonce)` off-chain with
+    /// the key registered via `update_signing_key`, where `contract` is this
+    /// escrow's address, so a signature can't be replayed on another escrow
+    /// or network. Each nonce can be used once.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to release
+    /// * `contributor` - Address receiving the full remaining amount
+    /// * `signature` - ed25519 signature over the message
+    /// * `nonce` - Unique value preventing replay
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds released
+    /// * `Err(Error::SigningKeyNotSet)` - No signing key registered
+    /// * `Err(Error::NonceAlreadyUsed)` - Nonce was consumed by an earlier release
+    ///
+    /// # Panics
+    /// * If the signature doesn't verify against the signing key
+    pub fn release_with_signature(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        signature: BytesN<64>,
+        nonce: u64,
+    ) -> Result<(), Error> {
+        let public_key: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SigningKey)
+            .ok_or(Error::SigningKeyNotSet)?;
+
+        if env.storage().persistent().has(&DataKey::UsedNonce(nonce)) {
+            return Err(Error::NonceAlreadyUsed);
+        }
+
+        let message = (
+            env.ledger().network_id(),
+            env.current_contract_address(),
+            bounty_id,
+            contributor.clone(),
+            nonce,
+        )
+            .to_xdr(&env);
+        env.crypto()
+            .ed25519_verify(&public_key, &message, &signature);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::UsedNonce(nonce), &true);
+
+        Self::release_funds_internal(env, bounty_id, contributor, None, None, None)
+    }
+
+    fn release_funds_internal(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        amount: Option<i128>,
+        caller: Option<Address>,
+        memo: Option<String>,
+    ) -> Result<(), Error> {
+        let start = env.ledger().timestamp();
+
+        // Guard against reentrant calls through the token
+        Self::enter_guard(&env, symbol_short!("release"));
+        if !env.storage().instance().has(&DataKey::Admin) {
+            Self::exit_guard(&env, symbol_short!("release"));
+            return Err(Error::NotInitialized);
+        }
+
+        // Signed releases were already authorized by the admin's signing key
+        let require_auth = caller.is_some();
+        let releaser = match caller {
+            Some(caller) => {
+                if !Self::is_authorized_to_release(env.clone(), caller.clone()) {
+                    monitoring::track_operation(&env, symbol_short!("release"), caller, false);
+                    Self::exit_guard(&env, symbol_short!("release"));
+                    return Err(Error::Unauthorized);
+                }
+                caller
+            }
+            None => env.storage().instance().get(&DataKey::Admin).unwrap(),
+        };
+
+        // Check if contract is paused
+        if Self::is_scope_paused(&env, PauseScope::Release) {
+            monitoring::track_operation(&env, symbol_short!("release"), releaser.clone(), false);
+            Self::exit_guard(&env, symbol_short!("release"));
+            return Err(Error::ContractPaused);
+        }
+
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, releaser.clone(), symbol_short!("release"));
+
+        if require_auth {
+            releaser.require_auth();
+        }
+
+        // Funds sent to the contract itself could never be withdrawn again
+        if contributor == env.current_contract_address() {
+            monitoring::track_operation(&env, symbol_short!("release"), releaser.clone(), false);
+            Self::exit_guard(&env, symbol_short!("release"));
+            return Err(Error::InvalidRecipient);
+        }
+        if let Err(error) = Self::ensure_allowed_recipient(&env, bounty_id, &contributor) {
+            monitoring::track_operation(&env, symbol_short!("release"), releaser.clone(), false);
+            Self::exit_guard(&env, symbol_short!("release"));
+            return Err(error);
+        }
+
+        // Verify bounty exists
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            monitoring::track_operation(&env, symbol_short!("release"), releaser.clone(), false);
+            Self::exit_guard(&env, symbol_short!("release"));
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get and verify escrow state
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        // Allow release from Locked or PartiallyReleased states
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyReleased
+        {
+            monitoring::track_operation(&env, symbol_short!("release"), releaser.clone(), false);
+            Self::exit_guard(&env, symbol_short!("release"));
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.disputed {
+            monitoring::track_operation(&env, symbol_short!("release"), releaser.clone(), false);
+            Self::exit_guard(&env, symbol_short!("release"));
+            return Err(Error::BountyDisputed);
+        }
+
+        if Self::ensure_acknowledged(&env, bounty_id, &contributor).is_err() {
+            monitoring::track_operation(&env, symbol_short!("release"), releaser.clone(), false);
+            Self::exit_guard(&env, symbol_short!("release"));
+            return Err(Error::ContributorNotAcknowledged);
+        }
+
+        // Determine payout amount and validate
+        let payout_amount = match amount {
+            Some(amt) => {
+                if amt <= 0 {
+                    monitoring::track_operation(
+                        &env,
+                        symbol_short!("release"),
+                        releaser.clone(),
+                        false,
+                    );
+                    Self::exit_guard(&env, symbol_short!("release"));
+                    return Err(Error::InvalidAmount);
+                }
+                if amt > escrow.remaining_amount {
+                    monitoring::track_operation(
+                        &env,
+                        symbol_short!("release"),
+                        releaser.clone(),
+                        false,
+                    );
+                    Self::exit_guard(&env, symbol_short!("release"));
+                    return Err(Error::InvalidAmount); // Attempt to over-pay
+                }
+                amt
+            }
+            None => escrow.remaining_amount, // Release full remaining amount
+        };
+
+        // With multisig on, enough signers must have approved this payout
+        if let Err(error) =
+            Self::consume_release_approval(&env, bounty_id, &contributor, payout_amount)
+        {
+            monitoring::track_operation(&env, symbol_short!("release"), releaser.clone(), false);
+            Self::exit_guard(&env, symbol_short!("release"));
+            return Err(error);
+        }
+
+        // Transfer funds to contributor
+        let token_addr = escrow.token.clone();
+        let client = token::Client::new(&env, &token_addr);
+
+        // Calculate and collect fee if enabled
+        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_amount = if fee_config.fee_enabled && fee_config.release_fee_rate > 0 {
+            Self::calculate_fee(payout_amount, fee_config.release_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = payout_amount - fee_amount;
+
+        // Ensure contract has sufficient funds
+        let contract_balance = client.balance(&env.current_contract_address());
+        if contract_balance < net_amount + fee_amount {
+            monitoring::track_operation(&env, symbol_short!("release"), releaser.clone(), false);
+            Self::exit_guard(&env, symbol_short!("release"));
+            return Err(Error::InsufficientFunds);
+        }
+
+        // Transfer net amount to contributor
+        client.transfer(&env.current_contract_address(), &contributor, &net_amount);
+
+        // Transfer fee to fee recipient if applicable
+        if fee_amount > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                &fee_amount,
+            );
+            events::emit_fee_collected(
+                &env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Release,
+                    amount: fee_amount,
+                    fee_rate: fee_config.release_fee_rate,
+                    recipient: fee_config.fee_recipient.clone(),
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        // Update escrow state
+        escrow.remaining_amount -= payout_amount;
+
+        // Add to payout history
+        let payout_record = PayoutRecord {
+            amount: payout_amount,
+            recipient: contributor.clone(),
+            timestamp: env.ledger().timestamp(),
+            memo: memo.clone(),
+        };
+        escrow.payout_history.push_back(payout_record);
+
+        // Update status
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released; // Fully released
+        } else {
+            escrow.status = EscrowStatus::PartiallyReleased; // Partially released
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        // Emit release event
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                bounty_id,
+                amount: net_amount,
+                recipient: contributor.clone(),
+                timestamp: env.ledger().timestamp(),
+                remaining_amount: escrow.remaining_amount,
+                memo,
+            },
+        );
+        Self::emit_lifecycle(
+            &env,
+            bounty_id,
+            symbol_short!("release"),
+            releaser.clone(),
+            net_amount,
+        );
+        Self::record_status_change(&env, bounty_id, escrow.status, releaser.clone());
+
+        Self::exit_guard(&env, symbol_short!("release"));
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("release"), releaser, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("release"), duration);
+        Ok(())
+    }
+
+    /// Creates or replaces a named escrow template (admin only).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `name` - Template name used by `lock_funds_from_template`
+    /// * `default_duration` - Seconds from lock time until the refund deadline
+    /// * `tag` - Label for off-chain grouping
+    /// * `allowed_refund_modes` - Refund modes bounties from this template accept
+    /// * `fee_exempt` - Whether bounties from this template skip the lock fee
+    ///
+    /// # Returns
+    /// * `Ok(())` - Template stored
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::InvalidDeadline)` - `default_duration` is zero
+    pub fn create_template(
+        env: Env,
+        name: Symbol,
+        default_duration: u64,
+        tag: Symbol,
+        allowed_refund_modes: Vec<RefundMode>,
+        fee_exempt: bool,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if default_duration == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+
+        let template = EscrowTemplate {
+            name: name.clone(),
+            default_duration,
+            tag,
+            allowed_refund_modes,
+            fee_exempt,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::Template(name), &template);
+
+        Ok(())
+    }
+
+    /// Retrieves a named escrow template.
+    pub fn get_template(env: Env, name: Symbol) -> Result<EscrowTemplate, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Template(name))
+            .ok_or(Error::TemplateNotFound)
+    }
+
+    /// Retrieves the template a bounty was locked from, if any.
+    pub fn get_bounty_template(env: Env, bounty_id: u64) -> Option<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BountyTemplate(bounty_id))
+    }
+
+    /// Locks funds using the defaults of a named template.
+    ///
+    /// The deadline is `now + template.default_duration`, the lock fee is
+    /// skipped for fee-exempt templates, and later refunds are restricted to
+    /// the template's `allowed_refund_modes`.
+    ///
+    /// # Returns
+    /// * `Err(Error::TemplateNotFound)` - No template with that name
+    /// * Any error returned by `lock_funds`
+    pub fn lock_funds_from_template(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        template_name: Symbol,
+    ) -> Result<(), Error> {
+        let template: EscrowTemplate = env
+            .storage()
+            .instance()
+            .get(&DataKey::Template(template_name.clone()))
+            .ok_or(Error::TemplateNotFound)?;
+
+        let deadline = env
+            .ledger()
+            .timestamp()
+            .saturating_add(template.default_duration);
+
+        Self::lock_funds_internal(
+            env.clone(),
+            depositor,
+            bounty_id,
+            amount,
+            deadline,
+            None,
+            template.fee_exempt,
+        )?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::BountyTemplate(bounty_id), &template_name);
+
+        Ok(())
+    }
+
+    /// Settles a bounty by splitting its remaining funds (admin only).
+    ///
+    /// Pays `contributor_amount` to the contributor and refunds whatever is left
+    /// to the depositor in one call, then moves the escrow to the terminal
+    /// `Settled` state. Useful for partially completed work that doesn't
+    /// warrant a full dispute. No release fee is taken on the contributor share.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to settle
+    /// * `contributor` - Address receiving the contributor share
+    /// * `contributor_amount` - Share paid to the contributor (0..=remaining)
+    ///
+    /// # Returns
+    /// * `Ok(())` - Both transfers succeeded
+    /// * `Err(Error::FundsNotLocked)` - Escrow already released, refunded or settled
+    /// * `Err(Error::InvalidAmount)` - `contributor_amount` outside `0..=remaining`
+    /// * `Err(Error::InvalidRecipient)` - Contributor is the contract itself
+    /// * `Err(Error::BountyDisputed)` - The bounty is under dispute
+    /// * `Err(Error::FundsCommittedToSchedule)` - Release schedules are still pending
+    ///
+    /// # Events
+    /// Emits `FundsReleased` for the contributor share and `FundsRefunded`
+    /// for the depositor share (each only when non-zero).
+    pub fn admin_split_settle(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        contributor_amount: i128,
+    ) -> Result<(), Error> {
+        let start = env.ledger().timestamp();
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+
+        if Self::is_scope_paused(&env, PauseScope::Release)
+            || Self::is_scope_paused(&env, PauseScope::Refund)
+        {
+            monitoring::track_operation(&env, symbol_short!("settle"), admin.clone(), false);
+            return Err(Error::ContractPaused);
+        }
+
+        admin.require_auth();
+
+        if contributor == env.current_contract_address() {
+            monitoring::track_operation(&env, symbol_short!("settle"), admin.clone(), false);
+            return Err(Error::InvalidRecipient);
+        }
+        if contributor_amount > 0 {
+            Self::ensure_allowed_recipient(&env, bounty_id, &contributor)?;
+            Self::ensure_acknowledged(&env, bounty_id, &contributor)?;
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked
+            && escrow.status != EscrowStatus::PartiallyReleased
+            && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            monitoring::track_operation(&env, symbol_short!("settle"), admin.clone(), false);
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.disputed {
+            monitoring::track_operation(&env, symbol_short!("settle"), admin.clone(), false);
+            return Err(Error::BountyDisputed);
+        }
+        // Settling empties the escrow, which would strand pending schedules
+        if Self::get_total_scheduled_amount(&env, bounty_id) > 0 {
+            monitoring::track_operation(&env, symbol_short!("settle"), admin.clone(), false);
+            return Err(Error::FundsCommittedToSchedule);
+        }
+
+        if contributor_amount < 0 || contributor_amount > escrow.remaining_amount {
+            monitoring::track_operation(&env, symbol_short!("settle"), admin.clone(), false);
+            return Err(Error::InvalidAmount);
+        }
+        let depositor_amount = escrow.remaining_amount - contributor_amount;
+        if contributor_amount > 0 {
+            if let Err(error) =
+                Self::consume_release_approval(&env, bounty_id, &contributor, contributor_amount)
+            {
+                monitoring::track_operation(&env, symbol_short!("settle"), admin.clone(), false);
+                return Err(error);
+            }
+        }
+
+        Self::enter_guard(&env, symbol_short!("settle"));
+
+        let token_addr = escrow.token.clone();
+        let client = token::Client::new(&env, &token_addr);
+        let contract_address = env.current_contract_address();
+        let timestamp = env.ledger().timestamp();
+
+        if contributor_amount > 0 {
+            client.transfer(&contract_address, &contributor, &contributor_amount);
+            escrow.payout_history.push_back(PayoutRecord {
+                amount: contributor_amount,
+                recipient: contributor.clone(),
+                timestamp,
+                memo: None,
+            });
+        }
+
+        if depositor_amount > 0 {
+            client.transfer(&contract_address, &escrow.depositor, &depositor_amount);
+            escrow.refund_history.push_back(RefundRecord {
+                amount: depositor_amount,
+                recipient: escrow.depositor.clone(),
+                mode: RefundMode::Partial,
+                timestamp,
+                memo: None,
+            });
+        }
+
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Settled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        if contributor_amount > 0 {
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    bounty_id,
+                    amount: contributor_amount,
+                    recipient: contributor,
+                    timestamp,
+                    remaining_amount: depositor_amount,
+                    memo: None,
+                },
+            );
+            Self::emit_lifecycle(
+                &env,
+                bounty_id,
+                symbol_short!("release"),
+                admin.clone(),
+                contributor_amount,
+            );
+        }
+
+        if depositor_amount > 0 {
+            emit_funds_refunded(
+                &env,
+                FundsRefunded {
+                    bounty_id,
+                    amount: depositor_amount,
+                    refund_to: escrow.depositor.clone(),
+                    timestamp,
+                    refund_mode: RefundMode::Partial,
+                    remaining_amount: 0,
+                    memo: None,
+                },
+            );
+            Self::emit_lifecycle(
+                &env,
+                bounty_id,
+                symbol_short!("refund"),
+                admin.clone(),
+                depositor_amount,
+            );
+        }
+        Self::record_status_change(&env, bounty_id, escrow.status, admin.clone());
+
+        Self::exit_guard(&env, symbol_short!("settle"));
+
+        monitoring::track_operation(&env, symbol_short!("settle"), admin, true);
+
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("settle"), duration);
+
+        Ok(())
+    }
+
+    /// Approve a refund before deadline (admin only).
+    /// This allows early refunds with admin approval. The approval expires
+    /// after the refund approval window (`set_refund_approval_window`).
+    pub fn approve_refund(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        recipient: Address,
+        mode: RefundMode,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if amount <= 0 || amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        // A new nonce supersedes whatever approval was issued before
+        let nonce = Self::get_refund_approval_nonce(env.clone(), bounty_id) + 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundApprovalNonce(bounty_id), &nonce);
+
+        let now = env.ledger().timestamp();
+        let approval = RefundApproval {
+            bounty_id,
+            amount,
+            recipient: recipient.clone(),
+            mode,
+            approved_by: admin.clone(),
+            approved_at: now,
+            expires_at: now.saturating_add(Self::get_refund_approval_window(env.clone())),
+            nonce,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundApproval(bounty_id), &approval);
+
+        Ok(())
+    }
+
+    /// Returns the pending refund approval for a bounty, if any. Expired
+    /// approvals are still returned; check `expires_at`.
+    pub fn get_refund_approval(env: Env, bounty_id: u64) -> Option<RefundApproval> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RefundApproval(bounty_id))
+    }
+
+    /// Nonce of the latest refund approval issued or consumed for a bounty.
+    /// Only an approval carrying this nonce can be used.
+    pub fn get_refund_approval_nonce(env: Env, bounty_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RefundApprovalNonce(bounty_id))
+            .unwrap_or(0)
+    }
+
+    /// Sets how long new refund approvals stay usable, in seconds (admin only).
+    /// Existing approvals keep the expiry they were created with.
+    pub fn set_refund_approval_window(env: Env, window: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if window == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundApprovalWindow, &window);
+        Ok(())
+    }
+
+    /// How long new refund approvals stay usable, in seconds
+    pub fn get_refund_approval_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundApprovalWindow)
+            .unwrap_or(DEFAULT_REFUND_APPROVAL_WINDOW)
+    }
+
+    /// Makes `refund` and `batch_refund` require the depositor's auth
+    /// (admin only). Off by default, so anyone can trigger a refund once
+    /// the deadline has passed.
+    pub fn set_refund_requires_depositor(env: Env, required: bool) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundRequiresDepositor, &required);
+        Ok(())
+    }
+
+    /// Whether refunds need the depositor's auth (view function)
+    pub fn refund_requires_depositor(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundRequiresDepositor)
+            .unwrap_or(false)
+    }
+
+    /// Sets how long after a bounty's deadline only the admin may refund it,
+    /// in seconds (admin only). 0, the default, makes refunds permissionless
+    /// as soon as the deadline passes.
+    pub fn set_refund_grace_period(env: Env, grace_period: u64) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundGracePeriod, &grace_period);
+        Ok(())
+    }
+
+    /// Seconds after the deadline during which only `admin_refund` works
+    pub fn get_refund_grace_period(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundGracePeriod)
+            .unwrap_or(0)
+    }
+
+    /// True while `now` falls between a deadline and the end of its grace period
+    fn in_refund_grace_period(env: &Env, deadline: u64, now: u64) -> bool {
+        now >= deadline && now < deadline.saturating_add(Self::get_refund_grace_period(env.clone()))
+    }
+
+    /// Refund funds with support for Full, Partial, and Custom refunds.
+    /// - Full: refunds all remaining funds to depositor
+    /// - Partial: refunds specified amount to depositor
+    /// - Custom: refunds specified amount to specified recipient (requires admin approval if before deadline)
+    ///
+    /// During the refund grace period after the deadline this returns
+    /// `Error::InGracePeriod`; only `admin_refund` can refund then.
+    pub fn refund(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+        recipient: Option<Address>,
+        mode: RefundMode,
+    ) -> Result<(), Error> {
+        Self::refund_internal(env, bounty_id, amount, recipient, mode, false, None)
+    }
+
+    /// Same as `refund`, recording `memo` (e.g. why the bounty was wound
+    /// down) in the refund history and the `FundsRefunded` event.
+    pub fn refund_with_memo(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+        recipient: Option<Address>,
+        mode: RefundMode,
+        memo: String,
+    ) -> Result<(), Error> {
+        Self::refund_internal(env, bounty_id, amount, recipient, mode, false, Some(memo))
+    }
+
+    /// Finalizes a bounty in one call, for keepers that shouldn't have to
+    /// branch client-side.
+    ///
+    /// With a `contributor` this is a full `release_funds` authorized by the
+    /// admin. Without one it is a permissionless full `refund` to the
+    /// depositor, which only works once the deadline has passed.
+    ///
+    /// # Returns
+    /// * `Err(Error::DeadlineNotPassed)` - No contributor given before the deadline
+    pub fn release_or_refund(
+        env: Env,
+        bounty_id: u64,
+        contributor: Option<Address>,
+    ) -> Result<(), Error> {
+        match contributor {
+            Some(contributor) => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(Error::NotInitialized)?;
+                Self::release_funds_internal(env, bounty_id, contributor, None, Some(admin), None)
+            }
+            None => {
+                Self::refund_internal(env, bounty_id, None, None, RefundMode::Full, false, None)
+            }
+        }
+    }
+
+    /// Same as `refund`, authorized by the admin, and also allowed during
+    /// the refund grace period (admin only).
+    pub fn admin_refund(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+        recipient: Option<Address>,
+        mode: RefundMode,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        Self::refund_internal(env, bounty_id, amount, recipient, mode, true, None)
+    }
+
+    fn refund_internal(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+        recipient: Option<Address>,
+        mode: RefundMode,
+        by_admin: bool,
+        memo: Option<String>,
+    ) -> Result<(), Error> {
+        let start = env.ledger().timestamp();
+
+        // Check if contract is paused
+        if Self::is_scope_paused(&env, PauseScope::Refund) {
+            let caller = env.current_contract_address();
+            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
+            return Err(Error::ContractPaused);
+        }
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            let caller = env.current_contract_address();
+            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get and verify escrow state
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        let caller = escrow.depositor.clone();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.disputed {
+            return Err(Error::BountyDisputed);
+        }
+        if Self::refund_requires_depositor(env.clone()) {
+            escrow.depositor.require_auth();
+        }
+
+        // Bounties locked from a template only accept its refund modes
+        if !Self::is_refund_mode_allowed(&env, bounty_id, mode) {
+            return Err(Error::RefundModeNotAllowed);
+        }
+
+        // Verify deadline has passed
+        let now = env.ledger().timestamp();
+        let is_before_deadline = now < escrow.deadline;
+        if !by_admin && Self::in_refund_grace_period(&env, escrow.deadline, now) {
+            return Err(Error::InGracePeriod);
+        }
+
+        // Determine refund amount and recipient
+        let refund_amount: i128;
+        let refund_recipient: Address;
+
+        match mode {
+            RefundMode::Full => {
+                refund_amount = escrow.remaining_amount;
+                refund_recipient = escrow.depositor.clone();
+                if is_before_deadline {
+                    return Err(Error::DeadlineNotPassed);
+                }
+            }
+            RefundMode::Partial => {
+                refund_amount = amount.unwrap_or(escrow.remaining_amount);
+                refund_recipient = escrow.depositor.clone();
+                if is_before_deadline {
+                    return Err(Error::DeadlineNotPassed);
+                }
+            }
+            RefundMode::Custom => {
+                refund_amount = amount.ok_or(Error::InvalidAmount)?;
+                refund_recipient = recipient.ok_or(Error::InvalidAmount)?;
+
+                // Custom refunds before deadline require admin approval
+                if is_before_deadline {
+                    if !env
+                        .storage()
+                        .persistent()
+                        .has(&DataKey::RefundApproval(bounty_id))
+                    {
+                        return Err(Error::RefundNotApproved);
+                    }
+                    let approval: RefundApproval = env
+                        .storage()
+                        .persistent()
+                        .get(&DataKey::RefundApproval(bounty_id))
+                        .unwrap();
+
+                    // Verify approval matches request
+                    if approval.amount != refund_amount
+                        || approval.recipient != refund_recipient
+                        || approval.mode != mode
+                        || approval.nonce != Self::get_refund_approval_nonce(env.clone(), bounty_id)
+                    {
+                        return Err(Error::RefundNotApproved);
+                    }
+                    if now > approval.expires_at {
+                        return Err(Error::ApprovalExpired);
+                    }
+
+                    // Clear approval after use and retire its nonce
+                    env.storage()
+                        .persistent()
+                        .remove(&DataKey::RefundApproval(bounty_id));
+                    env.storage().persistent().set(
+                        &DataKey::RefundApprovalNonce(bounty_id),
+                        &(approval.nonce + 1),
+                    );
+                }
+            }
+        }
+
+        // Validate amount
+        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Partial refunds must leave enough to cover the pending schedules
+        if mode != RefundMode::Full
+            && escrow.remaining_amount - refund_amount
+                < Self::get_total_scheduled_amount(&env, bounty_id)
+        {
+            return Err(Error::FundsCommittedToSchedule);
+        }
+
+        // Funds sent to the contract itself could never be withdrawn again
+        if refund_recipient == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+
+        Self::enter_guard(&env, symbol_short!("refund"));
+
+        Self::execute_refund(
+            &env,
+            bounty_id,
+            escrow,
+            refund_amount,
+            refund_recipient,
+            mode,
+            caller.clone(),
+            memo,
+        )?;
+
+        Self::exit_guard(&env, symbol_short!("refund"));
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("refund"), duration);
+
+        Ok(())
+    }
+
+    /// Bounties locked from a template only accept the template's refund modes
+    fn is_refund_mode_allowed(env: &Env, bounty_id: u64, mode: RefundMode) -> bool {
+        let Some(template_name) = env
+            .storage()
+            .persistent()
+            .get::<_, Symbol>(&DataKey::BountyTemplate(bounty_id))
+        else {
+            return true;
+        };
+        env.storage()
+            .instance()
+            .get::<_, EscrowTemplate>(&DataKey::Template(template_name))
+            .is_none_or(|template| template.allowed_refund_modes.contains(mode))
+    }
+
+    /// Moves `refund_amount` out of an escrow that has already passed the
+    /// refund checks: deducts the refund fee, transfers the rest to
+    /// `refund_recipient`, updates the escrow and emits the refund events.
+    ///
+    /// Returns the net amount sent to the recipient.
+    #[allow(clippy::too_many_arguments)]
+    fn execute_refund(
+        env: &Env,
+        bounty_id: u64,
+        mut escrow: Escrow,
+        refund_amount: i128,
+        refund_recipient: Address,
+        mode: RefundMode,
+        caller: Address,
+        memo: Option<String>,
+    ) -> Result<i128, Error> {
+        // Transfer funds back to depositor
+        let token_addr = escrow.token.clone();
+        let client = token::Client::new(env, &token_addr);
+
+        // Check contract balance
+        let contract_balance = client.balance(&env.current_contract_address());
+        if contract_balance < refund_amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        // Deduct the refund fee if enabled; the rate is capped well below
+        // 100% so the fee never exceeds the refund
+        let fee_config = Self::get_fee_config_internal(env);
+        let fee_amount = if fee_config.fee_enabled && fee_config.refund_fee_rate > 0 {
+            Self::calculate_fee(refund_amount, fee_config.refund_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = refund_amount - fee_amount;
+
+        // Transfer funds
+        client.transfer(
+            &env.current_contract_address(),
+            &refund_recipient,
+            &net_amount,
+        );
+
+        if fee_amount > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                &fee_amount,
+            );
+            events::emit_fee_collected(
+                env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Refund,
+                    amount: fee_amount,
+                    fee_rate: fee_config.refund_fee_rate,
+                    recipient: fee_config.fee_recipient.clone(),
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        // Update escrow state
+        escrow.remaining_amount -= refund_amount;
+
+        // Add to refund history
+        let refund_record = RefundRecord {
+            amount: refund_amount,
+            recipient: refund_recipient.clone(),
+            mode,
+            timestamp: env.ledger().timestamp(),
+            memo: memo.clone(),
+        };
+        escrow.refund_history.push_back(refund_record);
+
+        // Update status
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Refunded;
+        } else {
+            escrow.status = EscrowStatus::PartiallyRefunded;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        // Emit refund event
+        emit_funds_refunded(
+            env,
+            FundsRefunded {
+                bounty_id,
+                amount: net_amount,
+                refund_to: refund_recipient,
+                timestamp: env.ledger().timestamp(),
+                refund_mode: mode,
+                remaining_amount: escrow.remaining_amount,
+                memo,
+            },
+        );
+        Self::emit_lifecycle(
+            env,
+            bounty_id,
+            symbol_short!("refund"),
+            caller.clone(),
+            net_amount,
+        );
+        Self::record_status_change(env, bounty_id, escrow.status, caller);
+
+        Ok(net_amount)
+    }
+
+    /// Refunds the remaining funds of many expired bounties to their
+    /// depositors in one transaction.
+    ///
+    /// Bounties that aren't eligible are skipped rather than failing the
+    /// batch: missing, not Locked/PartiallyRefunded, disputed, deadline not
+    /// yet passed or still in its refund grace period, locked from a template
+    /// that doesn't allow `mode`, or with release schedules still pending.
+    ///
+    /// # Arguments
+    /// * `bounty_ids` - Bounties to refund, at most `get_max_batch_size`
+    /// * `mode` - `Full` or `Partial`; either refunds the whole remaining
+    ///   amount. `Custom` needs a per-bounty amount and recipient and is rejected
+    ///
+    /// # Returns
+    /// The number of bounties refunded and their ids, in input order
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if `bounty_ids` is empty or exceeds `get_max_batch_size`
+    /// * DuplicateBountyId - if a bounty id appears more than once
+    /// * RefundModeNotAllowed - if `mode` is `Custom`
+    /// * ContractPaused - if refunds are paused
+    pub fn batch_refund(
+        env: Env,
+        bounty_ids: Vec<u64>,
+        mode: RefundMode,
+    ) -> Result<(u32, Vec<u64>), Error> {
+        if bounty_ids.is_empty() || bounty_ids.len() > Self::max_batch_size(&env) {
+            return Err(Error::InvalidBatchSize);
+        }
+        if mode == RefundMode::Custom {
+            return Err(Error::RefundModeNotAllowed);
+        }
+        if Self::is_scope_paused(&env, PauseScope::Refund) {
+            return Err(Error::ContractPaused);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        Self::ensure_unique_bounty_ids(&env, bounty_ids.iter())?;
+
+        Self::enter_guard(&env, symbol_short!("refund"));
+
+        let now = env.ledger().timestamp();
+        let require_depositor_auth = Self::refund_requires_depositor(env.clone());
+        let mut refunded: Vec<u64> = vec![&env];
+        let mut total_amount: i128 = 0;
+        for bounty_id in bounty_ids.iter() {
+            let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<_, Escrow>(&DataKey::Escrow(bounty_id))
+            else {
+                continue;
+            };
+            if (escrow.status != EscrowStatus::Locked
+                && escrow.status != EscrowStatus::PartiallyRefunded)
+                || escrow.remaining_amount <= 0
+                || escrow.disputed
+                || now < escrow.deadline
+                || Self::in_refund_grace_period(&env, escrow.deadline, now)
+                || !Self::is_refund_mode_allowed(&env, bounty_id, mode)
+                || Self::get_total_scheduled_amount(&env, bounty_id) > 0
+            {
+                continue;
+            }
+
+            let refund_amount = escrow.remaining_amount;
+            let depositor = escrow.depositor.clone();
+            if require_depositor_auth {
+                depositor.require_auth();
+            }
+            let net_amount = Self::execute_refund(
+                &env,
+                bounty_id,
+                escrow,
+                refund_amount,
+                depositor.clone(),
+                mode,
+                depositor,
+                None,
+            )?;
+            total_amount += net_amount;
+            refunded.push_back(bounty_id);
+        }
+
+        emit_batch_funds_refunded(
+            &env,
+            BatchFundsRefunded {
+                count: refunded.len(),
+                total_amount,
+                timestamp: now,
+            },
+        );
+
+        Self::exit_guard(&env, symbol_short!("refund"));
+
+        Ok((refunded.len(), refunded))
+    }
+
+    /// Refunds part or all of a bounty to several recipients at once, e.g.
+    /// returning each co-funder's share. Every share is recorded as its own
+    /// Custom refund.
+    ///
+    /// Before the deadline the admin must authorize the split; after it the
+    /// depositor does, once the refund grace period is over.
+    ///
+    /// # Arguments
+    /// * `recipients` - Who receives each share, at most `get_max_batch_size`
+    /// * `amounts` - Gross share per recipient, in the same order
+    ///
+    /// # Returns
+    /// * `Err(Error::InvalidBatchSize)` - Empty, too long, or lengths differ
+    /// * `Err(Error::InvalidAmount)` - A share isn't positive or the total
+    ///   exceeds `remaining_amount`
+    /// * `Err(Error::FundsCommittedToSchedule)` - The split would strand
+    ///   pending release schedules
+    /// * `Err(Error::InGracePeriod)` - The deadline passed but the grace
+    ///   period hasn't, so only `admin_refund` can refund
+    pub fn refund_split(
+        env: Env,
+        bounty_id: u64,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> Result<(), Error> {
+        if recipients.is_empty()
+            || recipients.len() > Self::max_batch_size(&env)
+            || recipients.len() != amounts.len()
+        {
+            return Err(Error::InvalidBatchSize);
+        }
+        if Self::is_scope_paused(&env, PauseScope::Refund) {
+            return Err(Error::ContractPaused);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.disputed {
+            return Err(Error::BountyDisputed);
+        }
+        if !Self::is_refund_mode_allowed(&env, bounty_id, RefundMode::Custom) {
+            return Err(Error::RefundModeNotAllowed);
+        }
+
+        let now = env.ledger().timestamp();
+        if Self::in_refund_grace_period(&env, escrow.deadline, now) {
+            return Err(Error::InGracePeriod);
+        }
+        let caller = if now < escrow.deadline {
+            Self::require_admin(&env)?
+        } else {
+            escrow.depositor.require_auth();
+            escrow.depositor.clone()
+        };
+
+        let mut total: i128 = 0;
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            if recipient == env.current_contract_address() {
+                return Err(Error::InvalidRecipient);
+            }
+            total += amount;
+        }
+        if total > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+        if escrow.remaining_amount - total < Self::get_total_scheduled_amount(&env, bounty_id) {
+            return Err(Error::FundsCommittedToSchedule);
+        }
+
+        Self::enter_guard(&env, symbol_short!("refund"));
+
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            let escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(bounty_id))
+                .unwrap();
+            Self::execute_refund(
+                &env,
+                bounty_id,
+                escrow,
+                amount,
+                recipient,
+                RefundMode::Custom,
+                caller.clone(),
+                None,
+            )?;
+        }
+
+        Self::exit_guard(&env, symbol_short!("refund"));
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // View Functions (Read-only)
+    // ========================================================================
+
+    /// Retrieves escrow information for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(Escrow)` - The complete escrow record
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    ///
+    /// # Gas Cost
+    /// Very Low - Single storage read
+    ///
+    /// # Example
+    /// ```rust
+    /// let escrow_info = escrow_client.get_escrow_info(&42)?;
+    /// println!("Amount: {}", escrow_info.amount);
+    /// println!("Status: {:?}", escrow_info.status);
+    /// println!("Deadline: {}", escrow_info.deadline);
+    /// ```
+    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap())
+    }
+
+    /// Retrieves the escrows of several bounties in one call, in the order
+    /// requested, with `None` for bounties that don't exist.
+    ///
+    /// # Returns
+    /// * `Err(Error::InvalidBatchSize)` - More than `get_max_batch_size` ids requested
+    pub fn get_escrows(env: Env, bounty_ids: Vec<u64>) -> Result<Vec<Option<Escrow>>, Error> {
+        if bounty_ids.len() > Self::max_batch_size(&env) {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let mut escrows = Vec::new(&env);
+        for bounty_id in bounty_ids.iter() {
+            escrows.push_back(env.storage().persistent().get(&DataKey::Escrow(bounty_id)));
+        }
+        Ok(escrows)
+    }
+
+    /// Returns the current token balance held by the contract.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token` - Token to query; `None` means the contract's default token
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - Current contract token balance
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    ///
+    /// # Use Cases
+    /// - Monitoring total locked funds
+    /// - Verifying contract solvency
+    /// - Auditing and reconciliation
+    ///
+    /// # Gas Cost
+    /// Low - Token contract call
+    ///
+    /// # Example
+    /// ```rust
+    /// let balance = escrow_client.get_balance(&None)?;
+    /// println!("Total locked: {} stroops", balance);
+    /// ```
+    pub fn get_balance(env: Env, token: Option<Address>) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::NotInitialized);
+        }
+        let token_addr: Address =
+            token.unwrap_or_else(|| env.storage().instance().get(&DataKey::Token).unwrap());
+        let client = token::Client::new(&env, &token_addr);
+        Ok(client.balance(&env.current_contract_address()))
+    }
+
+    /// Retrieves the refund history for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(Vec<RefundRecord>)` - The refund history
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_refund_history(env: Env, bounty_id: u64) -> Result<Vec<RefundRecord>, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        Ok(escrow.refund_history)
+    }
+
+    /// Retrieves the payout history for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(Vec<PayoutRecord>)` - The payout history
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_payout_history(env: Env, bounty_id: u64) -> Result<Vec<PayoutRecord>, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        Ok(escrow.payout_history)
+    }
+
+    /// Gets refund eligibility information for a bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok((bool, bool, i128, Option<RefundApproval>))` - Tuple containing:
+    ///   - can_refund: Whether refund is possible
+    ///   - deadline_passed: Whether the deadline has passed
+    ///   - remaining: Remaining amount in escrow
+    ///   - approval: Optional refund approval if exists
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_refund_eligibility(
+        env: Env,
+        bounty_id: u64,
+    ) -> Result<(bool, bool, i128, Option<RefundApproval>), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        let now = env.ledger().timestamp();
+        let deadline_passed = now >= escrow.deadline;
+
+        let approval: Option<RefundApproval> = if env
+            .storage()
+            .persistent()
+            .has(&DataKey::RefundApproval(bounty_id))
+        {
+            Some(
+                env.storage()
+                    .persistent()
+                    .get(&DataKey::RefundApproval(bounty_id))
+                    .unwrap(),
+            )
+        } else {
+            None
+        };
+
+        // can_refund is true if:
+        // 1. Status is Locked or PartiallyRefunded AND
+        // 2. (deadline has passed OR there's an unexpired approval)
+        let can_refund = (escrow.status == EscrowStatus::Locked
+            || escrow.status == EscrowStatus::PartiallyRefunded)
+            && (deadline_passed
+                || approval
+                    .as_ref()
+                    .is_some_and(|approval| now <= approval.expires_at));
+
+        Ok((
+            can_refund,
+            deadline_passed,
+            escrow.remaining_amount,
+            approval,
+        ))
+    }
+
+    // ========================================================================
+    // Query Functions
+    // ========================================================================
+
+    /// Query bounties with filtering, sorting and pagination.
+    ///
+    /// Matches are sorted by `pagination.sort_by` (ties keep registry order)
+    /// before the page window is applied.
+    ///
+    /// # Performance
+    /// This function iterates through the registry. For large datasets, use small `pagination.limit` values
+    /// to prevent gas limit errors. This is designed for off-chain indexing.
+    /// Sorting by anything other than ascending bounty ID loads every match before paging.
+    pub fn get_bounties(
+        env: Env,
+        filter: EscrowFilter,
+        pagination: Pagination,
+    ) -> Vec<(u64, Escrow)> {
+        let registry: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::BountyRegistry)
+            .unwrap_or(vec![&env]);
+
+        let sorted = pagination.sort_by != SortBy::BountyId || pagination.descending;
+
+        // Matches in output order, with their sort keys when sorting
+        let mut matches: Vec<(u64, Escrow)> = vec![&env];
+        let mut keys: Vec<i128> = vec![&env];
+        let mut skipped: u64 = 0;
+
+        for i in 0..registry.len() {
+            // Check pagination limit (registry order only)
+            if !sorted && matches.len() >= pagination.limit {
+                break;
+            }
+
+            let bounty_id = registry.get(i).unwrap();
+
+            // Skip invalid IDs/missing data
+            let escrow: Escrow = match env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+                Some(escrow) => escrow,
+                None => continue,
+            };
+
+            if !Self::matches_filter(&escrow, &filter) {
+                continue;
+            }
+
+            if !sorted {
+                // Apply Pagination Skip
+                if skipped < pagination.start_index {
+                    skipped += 1;
+                    continue;
+                }
+                matches.push_back((bounty_id, escrow));
+                continue;
+            }
+
+            // Insertion sort; equal keys stay in registry order
+            let key = match pagination.sort_by {
+                SortBy::BountyId => bounty_id as i128,
+                SortBy::Amount => escrow.amount,
+                SortBy::Deadline => escrow.deadline as i128,
+            };
+            let mut pos = keys.len();
+            for j in 0..keys.len() {
+                let other = keys.get(j).unwrap();
+                let before = if pagination.descending {
+                    key > other
+                } else {
+                    key < other
+                };
+                if before {
+                    pos = j;
+                    break;
+                }
+            }
+            keys.insert(pos, key);
+            matches.insert(pos, (bounty_id, escrow));
+        }
+
+        if !sorted {
+            return matches;
+        }
+
+        // Apply pagination to the sorted matches
+        let mut result = vec![&env];
+        let start = pagination.start_index.min(matches.len() as u64) as u32;
+        let end = start.saturating_add(pagination.limit).min(matches.len());
+        for i in start..end {
+            result.push_back(matches.get(i).unwrap());
+        }
+
+        result
+    }
+
+    /// Query bounties with a `bounty_id` cursor instead of an offset.
+    ///
+    /// Returns up to `limit` bounties matching `filter` with `bounty_id > after_id`,
+    /// in ascending ID order. Pass the last returned ID as `after_id` to fetch the
+    /// next page (start with 0); an empty page means the scan is done. Unlike
+    /// `start_index`, the cursor doesn't shift when bounties are locked between calls.
+    ///
+    /// # Performance
+    /// Bounty IDs are caller-chosen, so the whole registry is scanned on every call.
+    pub fn get_bounties_after(
+        env: Env,
+        filter: EscrowFilter,
+        after_id: u64,
+        limit: u32,
+    ) -> Vec<(u64, Escrow)> {
+        let registry: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::BountyRegistry)
+            .unwrap_or(vec![&env]);
+
+        // The `limit` smallest matching IDs above the cursor, ascending
+        let mut result: Vec<(u64, Escrow)> = vec![&env];
+        if limit == 0 {
+            return result;
+        }
+
+        for bounty_id in registry.iter() {
+            if bounty_id <= after_id {
+                continue;
+            }
+            if result.len() >= limit && bounty_id >= result.last().unwrap().0 {
+                continue;
+            }
+
+            let escrow: Escrow = match env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+                Some(escrow) => escrow,
+                None => continue,
+            };
+            if !Self::matches_filter(&escrow, &filter) {
+                continue;
+            }
+
+            let mut pos = result.len();
+            for j in 0..result.len() {
+                if bounty_id < result.get(j).unwrap().0 {
+                    pos = j;
+                    break;
+                }
+            }
+            result.insert(pos, (bounty_id, escrow));
+            if result.len() > limit {
+                result.pop_back();
+            }
+        }
+
+        result
+    }
+
+    /// Bounties that still hold funds and whose deadline is at or before
+    /// `timestamp`, e.g. `now + 48h` for deadline reminders.
     ///
-    /// # State Changes
-    /// - Transfers `amount` tokens from depositor to contract
-    /// - Creates Escrow record in persistent storage
-    /// - Emits FundsLocked event
+    /// Same as `get_bounties` with a filter on the Locked, PartiallyReleased
+    /// and PartiallyRefunded statuses and `end_time = timestamp`, so
+    /// `pagination` works the same way.
+    pub fn get_bounties_expiring_before(
+        env: Env,
+        timestamp: u64,
+        pagination: Pagination,
+    ) -> Vec<(u64, Escrow)> {
+        let filter = EscrowFilter {
+            statuses: vec![
+                &env,
+                EscrowStatus::Locked as u32,
+                EscrowStatus::PartiallyReleased as u32,
+                EscrowStatus::PartiallyRefunded as u32,
+            ],
+            depositor: None,
+            min_amount: None,
+            max_amount: None,
+            start_time: None,
+            end_time: Some(timestamp),
+        };
+        Self::get_bounties(env, filter, pagination)
+    }
+
+    /// Count bounties matching `filter` without returning them.
+    ///
+    /// Uses the same matching as `get_bounties`.
+    ///
+    /// # Performance
+    /// This function iterates over ALL bounties. Use primarily for off-chain queries.
+    pub fn count_bounties(env: Env, filter: EscrowFilter) -> u32 {
+        let registry: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::BountyRegistry)
+            .unwrap_or(vec![&env]);
+
+        let mut count: u32 = 0;
+        for bounty_id in registry.iter() {
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<_, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if Self::matches_filter(&escrow, &filter) {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Check an escrow against every condition set in `filter`.
+    fn matches_filter(escrow: &Escrow, filter: &EscrowFilter) -> bool {
+        // Status filter
+        if !filter.statuses.is_empty() && !filter.statuses.contains(escrow.status as u32) {
+            return false;
+        }
+
+        // Depositor filter
+        if let Some(depositor) = &filter.depositor {
+            if &escrow.depositor != depositor {
+                return false;
+            }
+        }
+
+        // Amount range filter
+        if let Some(min) = filter.min_amount {
+            if escrow.amount < min {
+                return false;
+            }
+        }
+        if let Some(max) = filter.max_amount {
+            if escrow.amount > max {
+                return false;
+            }
+        }
+
+        // Date range filter (using deadline)
+        if let Some(start) = filter.start_time {
+            if escrow.deadline < start {
+                return false;
+            }
+        }
+        if let Some(end) = filter.end_time {
+            if escrow.deadline > end {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Get aggregate statistics for the contract.
+    ///
+    /// # Performance
+    /// This function iterates over ALL bounties. It is O(N) and may fail on-chain if N is large.
+    /// Use primarily for off-chain monitoring/indexing.
+    pub fn get_stats(env: Env) -> EscrowStats {
+        let registry: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::BountyRegistry)
+            .unwrap_or(vec![&env]);
+
+        let mut total_locked: i128 = 0;
+        let mut total_released: i128 = 0;
+        let mut total_refunded: i128 = 0;
+
+        for i in 0..registry.len() {
+            let bounty_id = registry.get(i).unwrap();
+            if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+                let escrow: Escrow = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Escrow(bounty_id))
+                    .unwrap();
+
+                match escrow.status {
+                    EscrowStatus::Locked => {
+                        total_locked += escrow.remaining_amount;
+                    }
+                    EscrowStatus::Released => {
+                        total_released += escrow.amount;
+                    }
+                    EscrowStatus::Refunded => {
+                        for record in escrow.refund_history.iter() {
+                            total_refunded += record.amount;
+                        }
+                    }
+                    EscrowStatus::PartiallyRefunded => {
+                        total_locked += escrow.remaining_amount;
+                        for record in escrow.refund_history.iter() {
+                            total_refunded += record.amount;
+                        }
+                    }
+                    EscrowStatus::PartiallyReleased => {
+                        total_locked += escrow.remaining_amount;
+                        for record in escrow.payout_history.iter() {
+                            total_released += record.amount;
+                        }
+                    }
+                    EscrowStatus::Settled
+                    | EscrowStatus::EmergencyWithdrawn
+                    | EscrowStatus::ForceClosed => {
+                        for record in escrow.payout_history.iter() {
+                            total_released += record.amount;
+                        }
+                        for record in escrow.refund_history.iter() {
+                            total_refunded += record.amount;
+                        }
+                    }
+                }
+            }
+        }
+
+        EscrowStats {
+            total_bounties: registry.len() as u64,
+            total_locked_amount: total_locked,
+            total_released_amount: total_released,
+            total_refunded_amount: total_refunded,
+        }
+    }
+
+    /// Batch lock funds for multiple bounties in a single transaction.
+    /// This improves gas efficiency by reducing transaction overhead.
+    ///
+    /// # Arguments
+    /// * `items` - Vector of LockFundsItem containing bounty_id, depositor, amount, and deadline
+    ///
+    /// # Returns
+    /// Number of successfully locked bounties
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if batch size exceeds `get_max_batch_size` or is zero
+    /// * BountyExists - if any bounty_id already exists
+    /// * InvalidAmount / InvalidDeadline - if any item breaks the config limits
+    /// * NotInitialized - if contract is not initialized
+    ///
+    /// # Note
+    /// This operation is atomic - if any item fails, the entire transaction reverts.
+    pub fn batch_lock_funds(env: Env, items: Vec<LockFundsItem>) -> Result<u32, Error> {
+        // Validate batch size
+        let batch_size = items.len();
+        if batch_size == 0 {
+            return Err(Error::InvalidBatchSize);
+        }
+        if batch_size > Self::max_batch_size(&env) {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        // Check if contract is paused
+        if Self::is_scope_paused(&env, PauseScope::Lock) {
+            return Err(Error::ContractPaused);
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let contract_address = env.current_contract_address();
+        let timestamp = env.ledger().timestamp();
+
+        // Validate all items before processing (all-or-nothing approach)
+        for item in items.iter() {
+            // Check if bounty already exists
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::Escrow(item.bounty_id))
+            {
+                return Err(Error::BountyExists);
+            }
+
+            // Validate amount
+            if item.amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            // Same deadline and limit checks as a single lock
+            if item.deadline <= timestamp {
+                return Err(Error::InvalidDeadline);
+            }
+            Self::check_config_limits(&env, item.amount, item.deadline)?;
+        }
+        Self::ensure_unique_bounty_ids(&env, items.iter().map(|item| item.bounty_id))?;
+
+        // Collect unique depositors and require auth once for each
+        // This prevents "frame is already authorized" errors when same depositor appears multiple times
+        let mut seen_depositors: Map<Address, ()> = Map::new(&env);
+        for item in items.iter() {
+            if !seen_depositors.contains_key(item.depositor.clone()) {
+                seen_depositors.set(item.depositor.clone(), ());
+                item.depositor.require_auth();
+            }
+        }
+
+        let mut registry: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::BountyRegistry)
+            .unwrap_or(vec![&env]);
+
+        // Process all items (atomic - all succeed or all fail)
+        let mut locked_count = 0u32;
+        for item in items.iter() {
+            // Transfer funds from depositor to contract
+            client.transfer(&item.depositor, &contract_address, &item.amount);
+
+            // Create escrow record
+            let escrow = Escrow {
+                depositor: item.depositor.clone(),
+                amount: item.amount,
+                status: EscrowStatus::Locked,
+                deadline: item.deadline,
+                refund_history: vec![&env],
+                payout_history: vec![&env],
+                remaining_amount: item.amount,
+                token: token_addr.clone(),
+                disputed: false,
+            };
+            // Store escrow
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+            registry.push_back(item.bounty_id);
+
+            // Emit individual event for each locked bounty
+            emit_funds_locked(
+                &env,
+                FundsLocked {
+                    bounty_id: item.bounty_id,
+                    amount: item.amount,
+                    depositor: item.depositor.clone(),
+                    deadline: item.deadline,
+                },
+            );
+            Self::emit_lifecycle(
+                &env,
+                item.bounty_id,
+                symbol_short!("lock"),
+                item.depositor.clone(),
+                item.amount,
+            );
+            Self::record_status_change(&env, item.bounty_id, escrow.status, item.depositor.clone());
+
+            locked_count += 1;
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::BountyRegistry, &registry);
+
+        // Emit batch event
+        emit_batch_funds_locked(
+            &env,
+            BatchFundsLocked {
+                count: locked_count,
+                total_amount: items.iter().map(|i| i.amount).sum(),
+                timestamp,
+            },
+        );
+
+        Ok(locked_count)
+    }
+
+    /// Fails with `DuplicateBountyId` on the first id that repeats.
+    ///
+    /// Single pass over the batch using a `Map` as a set, so the cost grows
+    /// linearly with the batch size instead of comparing every pair.
+    fn ensure_unique_bounty_ids(
+        env: &Env,
+        bounty_ids: impl Iterator<Item = u64>,
+    ) -> Result<(), Error> {
+        let mut seen: Map<u64, ()> = Map::new(env);
+        for bounty_id in bounty_ids {
+            if seen.contains_key(bounty_id) {
+                return Err(Error::DuplicateBountyId);
+            }
+            seen.set(bounty_id, ());
+        }
+        Ok(())
+    }
+
+    /// Batch release funds to multiple contributors in a single transaction.
+    /// This improves gas efficiency by reducing transaction overhead.
     ///
-    /// # Authorization
-    /// - Depositor must authorize the transaction
-    /// - Depositor must have sufficient token balance
-    /// - Depositor must have approved contract for token transfer
+    /// # Arguments
+    /// * `caller` - The admin or the configured payout key
+    /// * `items` - Vector of ReleaseFundsItem containing bounty_id and contributor address
     ///
-    /// # Security Considerations
-    /// - Bounty ID must be unique (prevents overwrites)
-    /// - Amount must be positive (enforced by token contract)
-    /// - Deadline should be reasonable (recommended: 7-90 days)
-    /// - Token transfer is atomic with state update
+    /// # Returns
+    /// Number of successfully released bounties
     ///
-    /// # Events
-    /// Emits: `FundsLocked { bounty_id, amount, depositor, deadline }`
+    /// # Errors
+    /// * InvalidBatchSize - if batch size exceeds `get_max_batch_size` or is zero
+    /// * BountyNotFound - if any bounty_id doesn't exist
+    /// * FundsNotLocked - if any bounty is not in Locked status
+    /// * Unauthorized - if caller is neither the admin nor the payout key
+    /// * InsufficientFunds - if the contract's token balance can't cover a release
+    /// * InsufficientApprovals - if multisig is on and an item lacks approvals
     ///
-    /// # Example
-    /// ```rust
-    /// let depositor = Address::from_string("GDEPOSIT...");
-    /// let amount = 1000_0000000; // 1000 USDC
-    /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60); // 30 days
+    /// # Note
+    /// This operation is atomic - if any item fails, the entire transaction reverts.
+    pub fn batch_release_funds(
+        env: Env,
+        caller: Address,
+        items: Vec<ReleaseFundsItem>,
+    ) -> Result<u32, Error> {
+        // Validate batch size
+        let batch_size = items.len();
+        if batch_size == 0 {
+            return Err(Error::InvalidBatchSize);
+        }
+        if batch_size > Self::max_batch_size(&env) {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        // Check if contract is paused
+        if Self::is_scope_paused(&env, PauseScope::Release) {
+            return Err(Error::ContractPaused);
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        if !Self::is_authorized_to_release(env.clone(), caller.clone()) {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+
+        let contract_address = env.current_contract_address();
+        let timestamp = env.ledger().timestamp();
+
+        // Validate all items before processing (all-or-nothing approach)
+        let mut total_amount: i128 = 0;
+        for item in items.iter() {
+            // Check if bounty exists
+            if !env
+                .storage()
+                .persistent()
+                .has(&DataKey::Escrow(item.bounty_id))
+            {
+                return Err(Error::BountyNotFound);
+            }
+
+            let escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(item.bounty_id))
+                .unwrap();
+
+            // Check if funds are locked
+            if escrow.status != EscrowStatus::Locked {
+                return Err(Error::FundsNotLocked);
+            }
+            if escrow.disputed {
+                return Err(Error::BountyDisputed);
+            }
+
+            if item.contributor == contract_address {
+                return Err(Error::InvalidRecipient);
+            }
+            Self::ensure_allowed_recipient(&env, item.bounty_id, &item.contributor)?;
+            Self::ensure_acknowledged(&env, item.bounty_id, &item.contributor)?;
+            Self::consume_release_approval(&env, item.bounty_id, &item.contributor, escrow.amount)?;
+
+            total_amount = total_amount
+                .checked_add(escrow.amount)
+                .ok_or(Error::InvalidAmount)?;
+        }
+        Self::ensure_unique_bounty_ids(&env, items.iter().map(|item| item.bounty_id))?;
+
+        // Process all items (atomic - all succeed or all fail)
+        let mut released_count = 0u32;
+        for item in items.iter() {
+            let mut escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(item.bounty_id))
+                .unwrap();
+
+            // Transfer funds to contributor, failing cleanly if the contract
+            // holds less than the escrow records say it should
+            let client = token::Client::new(&env, &escrow.token);
+            if client.balance(&contract_address) < escrow.amount {
+                return Err(Error::InsufficientFunds);
+            }
+            client.transfer(&contract_address, &item.contributor, &escrow.amount);
+
+            // Update escrow status
+            escrow.status = EscrowStatus::Released;
+            escrow.remaining_amount = 0;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+
+            // Emit individual event for each released bounty
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    bounty_id: item.bounty_id,
+                    amount: escrow.amount,
+                    recipient: item.contributor.clone(),
+                    timestamp,
+                    remaining_amount: escrow.remaining_amount,
+                    memo: None,
+                },
+            );
+            Self::emit_lifecycle(
+                &env,
+                item.bounty_id,
+                symbol_short!("release"),
+                caller.clone(),
+                escrow.amount,
+            );
+            Self::record_status_change(&env, item.bounty_id, escrow.status, caller.clone());
+
+            released_count += 1;
+        }
+
+        // Emit batch event
+        emit_batch_funds_released(
+            &env,
+            BatchFundsReleased {
+                count: released_count,
+                total_amount,
+                timestamp,
+            },
+        );
+
+        Ok(released_count)
+    }
+
+    // ========================================================================
+    // Release Schedule Functions
+    // ========================================================================
+
+    /// Schedules part of a bounty's remaining funds for release at a later time
+    /// (admin or `ScheduleManager`).
     ///
-    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline)?;
-    /// // Funds are now locked and can be released or refunded
-    /// ```
+    /// The scheduled amount stays in escrow until the schedule is executed.
+    /// The total of all pending schedules can never exceed `remaining_amount`.
     ///
-    /// # Gas Cost
-    /// Medium - Token transfer + storage write + event emission
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - The admin or a `ScheduleManager`
+    /// * `bounty_id` - The bounty to schedule a release from
+    /// * `amount` - Amount to release when the schedule executes
+    /// * `release_timestamp` - Earliest time the schedule can be executed automatically
+    /// * `recipient` - Address receiving the scheduled amount
     ///
-    /// # Common Pitfalls
-    /// - Forgetting to approve token contract before calling
-    /// - Using a bounty ID that already exists
-    /// - Setting deadline in the past or too far in the future
-    pub fn lock_funds(
+    /// # Returns
+    /// * `Ok(())` - Schedule created
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't Locked or PartiallyReleased
+    /// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(Error::InvalidDeadline)` - `release_timestamp` is in the past
+    /// * `Err(Error::InsufficientFunds)` - Pending schedules would exceed the remaining amount
+    ///
+    /// # Events
+    /// Emits: `ScheduleCreated`
+    pub fn create_release_schedule(
         env: Env,
-        depositor: Address,
+        caller: Address,
         bounty_id: u64,
         amount: i128,
-        deadline: u64,
+        release_timestamp: u64,
+        recipient: Address,
     ) -> Result<(), Error> {
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
-
-        let start = env.ledger().timestamp();
-        let caller = depositor.clone();
+        Self::require_role(&env, roles::Role::ScheduleManager, &caller)?;
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+        if Self::is_scope_paused(&env, PauseScope::Schedule) {
             return Err(Error::ContractPaused);
         }
 
-        // Verify depositor authorization
-        depositor.require_auth();
+        if recipient == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+        Self::ensure_allowed_recipient(&env, bounty_id, &recipient)?;
+        Self::ensure_acknowledged(&env, bounty_id, &recipient)?;
 
-        // Ensure contract is initialized
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyReleased
+        {
+            return Err(Error::FundsNotLocked);
         }
-        env.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &true);
 
         if amount <= 0 {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::InvalidAmount);
         }
 
-        if deadline <= env.ledger().timestamp() {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        if release_timestamp < env.ledger().timestamp() {
             return Err(Error::InvalidDeadline);
         }
-        if !env.storage().instance().has(&DataKey::Admin) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::NotInitialized);
-        }
 
-        // Prevent duplicate bounty IDs
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyExists);
+        let scheduled = Self::get_total_scheduled_amount(&env, bounty_id);
+        if scheduled + amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
         }
 
-        // Get token contract and transfer funds
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
-            Self::calculate_fee(amount, fee_config.lock_fee_rate)
-        } else {
-            0
-        };
-        let net_amount = amount - fee_amount;
+        // Under multisig the signers approve a schedule when it is created;
+        // executing it later needs no further approval
+        Self::consume_release_approval(&env, bounty_id, &recipient, amount)?;
 
-        // Transfer net amount from depositor to contract
-        client.transfer(&depositor, &env.current_contract_address(), &net_amount);
+        let schedule_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextScheduleId(bounty_id))
+            .unwrap_or(1);
 
-        // Transfer fee to fee recipient if applicable
-        if fee_amount > 0 {
-            client.transfer(&depositor, &fee_config.fee_recipient, &fee_amount);
-            events::emit_fee_collected(
-                &env,
-                events::FeeCollected {
-                    operation_type: events::FeeOperationType::Lock,
-                    amount: fee_amount,
-                    fee_rate: fee_config.lock_fee_rate,
-                    recipient: fee_config.fee_recipient.clone(),
-                    timestamp: env.ledger().timestamp(),
-                },
-            );
-        }
+        // Read the id list before bumping NextScheduleId so the legacy
+        // fallback doesn't already include the id being created.
+        let mut schedule_ids = Self::get_schedule_ids(&env, bounty_id);
 
-        // Create escrow record
-        let escrow = Escrow {
-            depositor: depositor.clone(),
-            amount: net_amount, // Store net amount (after fee)
-            status: EscrowStatus::Locked,
-            deadline,
-            refund_history: vec![&env],
-            payout_history: vec![&env],
-            remaining_amount: amount,
+        let schedule = ReleaseSchedule {
+            schedule_id,
+            amount,
+            release_timestamp,
+            recipient: recipient.clone(),
+            released: false,
+            released_at: None,
+            released_by: None,
         };
-
-        // Store in persistent storage with extended TTL
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // Update registry
-        let mut registry: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey::BountyRegistry)
-            .unwrap_or(vec![&env]);
-        registry.push_back(bounty_id);
+            .set(&DataKey::ReleaseSchedule(bounty_id, schedule_id), &schedule);
         env.storage()
-            .instance()
-            .set(&DataKey::BountyRegistry, &registry);
+            .persistent()
+            .set(&DataKey::NextScheduleId(bounty_id), &(schedule_id + 1));
+        schedule_ids.push_back(schedule_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScheduleIds(bounty_id), &schedule_ids);
 
-        // Emit event for off-chain indexing
-        emit_funds_locked(
+        events::emit_schedule_created(
             &env,
-            FundsLocked {
+            events::ScheduleCreated {
                 bounty_id,
-                amount: net_amount, // Emit net amount (after fee)
-                depositor: depositor.clone(),
-                deadline,
+                schedule_id,
+                amount,
+                release_timestamp,
+                recipient,
+                created_by: caller.clone(),
             },
         );
+        Self::emit_lifecycle(&env, bounty_id, symbol_short!("schedule"), caller, amount);
+
+        Ok(())
+    }
+
+    /// Executes a due release schedule. Anyone can trigger this once
+    /// `release_timestamp` has passed.
+    ///
+    /// # Returns
+    /// * `Err(Error::ScheduleNotDue)` - The release timestamp hasn't been reached
+    /// * `Err(Error::ScheduleAlreadyReleased)` - Schedule was already executed
+    pub fn release_schedule_automatic(
+        env: Env,
+        bounty_id: u64,
+        schedule_id: u64,
+    ) -> Result<(), Error> {
+        let schedule = Self::get_release_schedule(env.clone(), bounty_id, schedule_id)?;
+        if env.ledger().timestamp() < schedule.release_timestamp {
+            return Err(Error::ScheduleNotDue);
+        }
+        let caller = env.current_contract_address();
+        Self::execute_schedule(&env, bounty_id, schedule, caller, ReleaseType::Automatic)
+    }
+
+    /// Executes every due schedule of each listed bounty, e.g. from a keeper
+    /// sweeping many vesting bounties. Anyone can call it.
+    ///
+    /// Bounties with nothing due, or whose schedules can't be released right
+    /// now (disputed, no longer locked, short on funds), are skipped rather
+    /// than failing the batch; each skip is counted in `get_error_breakdown`.
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - Number of schedules released
+    /// * `Err(Error::InvalidBatchSize)` - `bounty_ids` is empty or exceeds `get_max_batch_size`
+    /// * `Err(Error::ContractPaused)` - Schedule releases are paused
+    pub fn batch_release_due_schedules(env: Env, bounty_ids: Vec<u64>) -> Result<u32, Error> {
+        if bounty_ids.is_empty() || bounty_ids.len() > Self::max_batch_size(&env) {
+            return Err(Error::InvalidBatchSize);
+        }
+        if Self::is_scope_paused(&env, PauseScope::Schedule) {
+            return Err(Error::ContractPaused);
+        }
 
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        Self::enter_guard(&env, symbol_short!("release"));
+
+        let caller = env.current_contract_address();
+        let mut released: u32 = 0;
+        for bounty_id in bounty_ids.iter() {
+            for schedule in Self::get_due_schedules(env.clone(), bounty_id).iter() {
+                if let Err(err) = Self::release_schedule_unguarded(
+                    &env,
+                    bounty_id,
+                    schedule,
+                    caller.clone(),
+                    ReleaseType::Automatic,
+                ) {
+                    monitoring::track_error(&env, err as u32);
+                    break;
+                }
+                released += 1;
+            }
+        }
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("lock"), caller, true);
+        Self::exit_guard(&env, symbol_short!("release"));
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("lock"), duration);
+        Ok(released)
+    }
 
-        Ok(())
+    /// Executes a release schedule ahead of time (admin or `ScheduleManager`).
+    ///
+    /// # Returns
+    /// * `Err(Error::ScheduleAlreadyReleased)` - Schedule was already executed
+    pub fn release_schedule_manual(
+        env: Env,
+        caller: Address,
+        bounty_id: u64,
+        schedule_id: u64,
+    ) -> Result<(), Error> {
+        Self::require_role(&env, roles::Role::ScheduleManager, &caller)?;
+
+        let schedule = Self::get_release_schedule(env.clone(), bounty_id, schedule_id)?;
+        Self::execute_schedule(&env, bounty_id, schedule, caller, ReleaseType::Manual)
     }
 
-    /// Releases escrowed funds to a contributor.
+    /// Releases whatever part of the remaining amount isn't reserved by a
+    /// pending schedule (admin only).
+    ///
+    /// Pending schedules are left untouched and their funds stay in escrow.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to release funds for
-    /// * `contributor` - Address to receive the funds
+    /// * `bounty_id` - The bounty to release from
+    /// * `contributor` - Address receiving the unscheduled remainder
     ///
     /// # Returns
-    /// * `Ok(())` - Funds successfully released
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    /// * `Err(Error::Unauthorized)` - Caller is not the admin
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
-    ///
-    /// # State Changes
-    /// - Transfers tokens from contract to contributor
-    /// - Updates escrow status to Released
-    /// - Emits FundsReleased event
-    ///
-    /// # Authorization
-    /// - **CRITICAL**: Only admin can call this function
-    /// - Admin address must match initialization value
-    ///
-    /// # Security Considerations
-    /// - This is the most security-critical function
-    /// - Admin should verify task completion off-chain before calling
-    /// - Once released, funds cannot be retrieved
-    /// - Recipient address should be verified carefully
-    /// - Consider implementing multi-sig for admin
+    /// * `Ok(())` - Remainder released
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't Locked or PartiallyReleased
+    /// * `Err(Error::InvalidAmount)` - Nothing is left outside the pending schedules
     ///
     /// # Events
-    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }`
-    ///
-    /// # Example
-    /// ```rust
-    /// // After verifying task completion off-chain:
-    /// let contributor = Address::from_string("GCONTRIB...");
-    ///
-    /// // Admin calls release
-    /// escrow_client.release_funds(&42, &contributor)?;
-    /// // Funds transferred to contributor, escrow marked as Released
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Medium - Token transfer + storage update + event emission
-    ///
-    /// # Best Practices
-    /// 1. Verify contributor identity off-chain
-    /// 2. Confirm task completion before release
-    /// 3. Log release decisions in backend system
-    /// 4. Monitor release events for anomalies
-    /// 5. Consider implementing release delays for high-value bounties
-    pub fn release_funds(
+    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp, remaining_amount }`
+    pub fn release_unscheduled_remainder(
         env: Env,
         bounty_id: u64,
         contributor: Address,
-        amount: Option<i128>, // Optional partial amount
     ) -> Result<(), Error> {
-        let start = env.ledger().timestamp();
-
-        // Ensure contract is initialized
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
-        }
-        env.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &true);
         if !env.storage().instance().has(&DataKey::Admin) {
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::NotInitialized);
         }
-
-        // Verify admin authorization
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        if Self::is_scope_paused(&env, PauseScope::Release) {
             return Err(Error::ContractPaused);
         }
 
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, admin.clone());
-
         admin.require_auth();
 
-        // Verify bounty exists
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyNotFound);
+        if contributor == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
         }
+        Self::ensure_allowed_recipient(&env, bounty_id, &contributor)?;
+        Self::ensure_acknowledged(&env, bounty_id, &contributor)?;
 
-        // Get and verify escrow state
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .ok_or(Error::BountyNotFound)?;
 
-        // Allow release from Locked or PartiallyReleased states
         if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyReleased
         {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::FundsNotLocked);
         }
+        if escrow.disputed {
+            return Err(Error::BountyDisputed);
+        }
 
-        // Determine payout amount and validate
-        let payout_amount = match amount {
-            Some(amt) => {
-                if amt <= 0 {
-                    monitoring::track_operation(
-                        &env,
-                        symbol_short!("release"),
-                        admin.clone(),
-                        false,
-                    );
-                    env.storage().instance().remove(&DataKey::ReentrancyGuard);
-                    return Err(Error::InvalidAmount);
-                }
-                if amt > escrow.remaining_amount {
-                    monitoring::track_operation(
-                        &env,
-                        symbol_short!("release"),
-                        admin.clone(),
-                        false,
-                    );
-                    env.storage().instance().remove(&DataKey::ReentrancyGuard);
-                    return Err(Error::InvalidAmount); // Attempt to over-pay
-                }
-                amt
-            }
-            None => escrow.remaining_amount, // Release full remaining amount
-        };
+        let unscheduled =
+            escrow.remaining_amount - Self::get_total_scheduled_amount(&env, bounty_id);
+        if unscheduled <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::consume_release_approval(&env, bounty_id, &contributor, unscheduled)?;
 
-        // Transfer funds to contributor
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        Self::enter_guard(&env, symbol_short!("release"));
+
+        let token_addr = escrow.token.clone();
         let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &contributor, &unscheduled);
 
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.release_fee_rate > 0 {
-            Self::calculate_fee(payout_amount, fee_config.release_fee_rate)
+        let timestamp = env.ledger().timestamp();
+        escrow.remaining_amount -= unscheduled;
+        escrow.payout_history.push_back(PayoutRecord {
+            amount: unscheduled,
+            recipient: contributor.clone(),
+            timestamp,
+            memo: None,
+        });
+        escrow.status = if escrow.remaining_amount == 0 {
+            EscrowStatus::Released
         } else {
-            0
+            EscrowStatus::PartiallyReleased
         };
-        let net_amount = payout_amount - fee_amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
 
-        // Ensure contract has sufficient funds
-        let contract_balance = client.balance(&env.current_contract_address());
-        if contract_balance < net_amount + fee_amount {
-            return Err(Error::InsufficientFunds);
-        }
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                bounty_id,
+                amount: unscheduled,
+                recipient: contributor,
+                timestamp,
+                remaining_amount: escrow.remaining_amount,
+                memo: None,
+            },
+        );
+        Self::emit_lifecycle(
+            &env,
+            bounty_id,
+            symbol_short!("release"),
+            admin.clone(),
+            unscheduled,
+        );
+        Self::record_status_change(&env, bounty_id, escrow.status, admin.clone());
 
-        // Transfer net amount to contributor
-        client.transfer(&env.current_contract_address(), &contributor, &net_amount);
+        Self::exit_guard(&env, symbol_short!("release"));
 
-        // Transfer fee to fee recipient if applicable
-        if fee_amount > 0 {
-            client.transfer(
-                &env.current_contract_address(),
-                &fee_config.fee_recipient,
-                &fee_amount,
-            );
-            events::emit_fee_collected(
-                &env,
-                events::FeeCollected {
-                    operation_type: events::FeeOperationType::Release,
-                    amount: fee_amount,
-                    fee_rate: fee_config.release_fee_rate,
-                    recipient: fee_config.fee_recipient.clone(),
-                    timestamp: env.ledger().timestamp(),
-                },
-            );
-        }
+        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
 
-        // Update escrow state
-        escrow.remaining_amount -= payout_amount;
+        Ok(())
+    }
 
-        // Add to payout history
-        let payout_record = PayoutRecord {
-            amount: payout_amount,
-            recipient: contributor.clone(),
-            timestamp: env.ledger().timestamp(),
-        };
-        escrow.payout_history.push_back(payout_record);
+    /// Sends whatever is left in escrow after every release schedule has
+    /// executed, e.g. rounding dust, to `recipient` and closes the bounty
+    /// as Released (admin only). A recipient other than the depositor must
+    /// be on the bounty's allow-list.
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - The amount reclaimed
+    /// * `Err(Error::ScheduleNotFound)` - The bounty never had schedules
+    /// * `Err(Error::SchedulesPending)` - Some schedules haven't executed yet
+    /// * `Err(Error::InvalidAmount)` - Nothing is left in escrow
+    /// * `Err(Error::Unauthorized)` - `recipient` isn't on the allow-list
+    ///
+    /// # Events
+    /// Emits: `ResidualReclaimed { bounty_id, amount, recipient, reclaimed_by, timestamp }`
+    pub fn reclaim_residual(env: Env, bounty_id: u64, recipient: Address) -> Result<i128, Error> {
+        let admin = Self::require_admin(&env)?;
 
-        // Update status
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Released; // Fully released
-        } else {
-            escrow.status = EscrowStatus::PartiallyReleased; // Partially released
+        if Self::is_scope_paused(&env, PauseScope::Release) {
+            return Err(Error::ContractPaused);
+        }
+        if recipient == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyReleased
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.disputed {
+            return Err(Error::BountyDisputed);
+        }
+        if Self::get_schedule_ids(&env, bounty_id).is_empty() {
+            return Err(Error::ScheduleNotFound);
+        }
+        if !Self::get_pending_schedules(env.clone(), bounty_id).is_empty() {
+            return Err(Error::SchedulesPending);
         }
+        let residual = escrow.remaining_amount;
+        if residual <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if recipient != escrow.depositor {
+            Self::ensure_allowed_recipient(&env, bounty_id, &recipient)?;
+        }
+        Self::consume_release_approval(&env, bounty_id, &recipient, residual)?;
+
+        Self::enter_guard(&env, symbol_short!("release"));
 
+        let client = token::Client::new(&env, &escrow.token);
+        client.transfer(&env.current_contract_address(), &recipient, &residual);
+
+        let timestamp = env.ledger().timestamp();
+        escrow.remaining_amount = 0;
+        escrow.payout_history.push_back(PayoutRecord {
+            amount: residual,
+            recipient: recipient.clone(),
+            timestamp,
+            memo: None,
+        });
+        escrow.status = EscrowStatus::Released;
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
 
-        // Emit release event
-        emit_funds_released(
+        events::emit_residual_reclaimed(
             &env,
-            FundsReleased {
+            events::ResidualReclaimed {
                 bounty_id,
-                amount: net_amount,
-                recipient: contributor.clone(),
-                timestamp: env.ledger().timestamp(),
-                remaining_amount: escrow.remaining_amount,
+                amount: residual,
+                recipient,
+                reclaimed_by: admin.clone(),
+                timestamp,
             },
         );
+        Self::emit_lifecycle(
+            &env,
+            bounty_id,
+            symbol_short!("release"),
+            admin.clone(),
+            residual,
+        );
+        Self::record_status_change(&env, bounty_id, escrow.status, admin);
 
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
-
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
+        Self::exit_guard(&env, symbol_short!("release"));
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("release"), duration);
-        Ok(())
+        Ok(residual)
     }
 
-    /// Approve a refund before deadline (admin only).
-    /// This allows early refunds with admin approval.
-    pub fn approve_refund(
+    /// Retrieves a single release schedule.
+    pub fn get_release_schedule(
         env: Env,
         bounty_id: u64,
-        amount: i128,
-        recipient: Address,
-        mode: RefundMode,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        schedule_id: u64,
+    ) -> Result<ReleaseSchedule, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseSchedule(bounty_id, schedule_id))
+            .ok_or(Error::ScheduleNotFound)
+    }
+
+    /// Retrieves every release schedule for a bounty, released or not,
+    /// in schedule id order.
+    pub fn get_all_release_schedules(env: Env, bounty_id: u64) -> Vec<ReleaseSchedule> {
+        let mut schedules = Vec::new(&env);
+        for schedule_id in Self::get_schedule_ids(&env, bounty_id).iter() {
+            if let Some(schedule) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, ReleaseSchedule>(&DataKey::ReleaseSchedule(bounty_id, schedule_id))
+            {
+                schedules.push_back(schedule);
+            }
         }
+        schedules
+    }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    /// Ids of the schedules stored for a bounty (internal helper)
+    ///
+    /// Bounties scheduled before the id list existed fall back to every id
+    /// below `NextScheduleId`.
+    fn get_schedule_ids(env: &Env, bounty_id: u64) -> Vec<u64> {
+        if let Some(ids) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ScheduleIds(bounty_id))
+        {
+            return ids;
+        }
+
+        let next_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextScheduleId(bounty_id))
+            .unwrap_or(1);
+        let mut ids = Vec::new(env);
+        for schedule_id in 1..next_id {
+            ids.push_back(schedule_id);
+        }
+        ids
+    }
+
+    /// Retrieves the release schedules for a bounty that haven't been executed yet.
+    pub fn get_pending_schedules(env: Env, bounty_id: u64) -> Vec<ReleaseSchedule> {
+        let mut pending = Vec::new(&env);
+        for schedule in Self::get_all_release_schedules(env.clone(), bounty_id).iter() {
+            if !schedule.released {
+                pending.push_back(schedule);
+            }
+        }
+        pending
+    }
+
+    /// Retrieves the pending schedules for a bounty whose release time has passed.
+    pub fn get_due_schedules(env: Env, bounty_id: u64) -> Vec<ReleaseSchedule> {
+        let now = env.ledger().timestamp();
+        let mut due = Vec::new(&env);
+        for schedule in Self::get_pending_schedules(env.clone(), bounty_id).iter() {
+            if schedule.release_timestamp <= now {
+                due.push_back(schedule);
+            }
+        }
+        due
+    }
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+    /// Sums the amounts of a bounty's pending schedules whose release time
+    /// has passed, i.e. what `release_schedule_automatic` could pay out now.
+    pub fn get_claimable_total(env: Env, bounty_id: u64) -> i128 {
+        let mut total: i128 = 0;
+        for schedule in Self::get_due_schedules(env, bounty_id).iter() {
+            total += schedule.amount;
         }
+        total
+    }
 
-        let escrow: Escrow = env
+    /// Sums the amounts of a bounty's unreleased schedules, i.e. the part of
+    /// the escrow already committed to future releases.
+    pub fn get_scheduled_total(env: Env, bounty_id: u64) -> i128 {
+        Self::get_total_scheduled_amount(&env, bounty_id)
+    }
+
+    /// Returns the part of a bounty's remaining amount not committed to any
+    /// unreleased schedule. Unknown bounties report 0.
+    pub fn get_unscheduled_remaining(env: Env, bounty_id: u64) -> i128 {
+        let remaining = env
             .storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            .map(|escrow| escrow.remaining_amount)
+            .unwrap_or(0);
+        remaining - Self::get_total_scheduled_amount(&env, bounty_id)
+    }
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
+    /// Retrieves the executed schedule releases for a bounty.
+    pub fn get_release_history(env: Env, bounty_id: u64) -> Vec<ReleaseHistory> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseHistory(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
 
-        if amount <= 0 || amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+    /// Retrieves one page of a bounty's executed schedule releases.
+    ///
+    /// Entries are in release order, newest first if `pagination.descending`
+    /// is set; `pagination.sort_by` is ignored.
+    pub fn get_release_history_paged(
+        env: Env,
+        bounty_id: u64,
+        pagination: Pagination,
+    ) -> Vec<ReleaseHistory> {
+        let history = Self::get_release_history(env.clone(), bounty_id);
+        let len = history.len();
+
+        let mut page = Vec::new(&env);
+        let start = pagination.start_index.min(len as u64) as u32;
+        let end = start.saturating_add(pagination.limit).min(len);
+        for i in start..end {
+            let index = if pagination.descending {
+                len - 1 - i
+            } else {
+                i
+            };
+            page.push_back(history.get(index).unwrap());
         }
+        page
+    }
 
-        let approval = RefundApproval {
-            bounty_id,
-            amount,
-            recipient: recipient.clone(),
-            mode,
-            approved_by: admin.clone(),
-            approved_at: env.ledger().timestamp(),
-        };
+    /// Number of executed schedule releases recorded for a bounty
+    pub fn get_release_history_count(env: Env, bounty_id: u64) -> u32 {
+        Self::get_release_history(env, bounty_id).len()
+    }
 
+    /// Retrieves every status transition of a bounty, oldest first: the
+    /// lock, each release or refund, and any settlement or emergency drain.
+    pub fn get_status_history(env: Env, bounty_id: u64) -> Vec<StatusChange> {
         env.storage()
             .persistent()
-            .set(&DataKey::RefundApproval(bounty_id), &approval);
+            .get(&DataKey::StatusHistory(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
 
-        Ok(())
+    /// Sums the amounts of all unreleased schedules for a bounty.
+    fn get_total_scheduled_amount(env: &Env, bounty_id: u64) -> i128 {
+        let mut total: i128 = 0;
+        for schedule in Self::get_pending_schedules(env.clone(), bounty_id).iter() {
+            total += schedule.amount;
+        }
+        total
     }
 
-    /// Refund funds with support for Full, Partial, and Custom refunds.
-    /// - Full: refunds all remaining funds to depositor
-    /// - Partial: refunds specified amount to depositor
-    /// - Custom: refunds specified amount to specified recipient (requires admin approval if before deadline)
-    pub fn refund(
-        env: Env,
+    /// Transfers a schedule's amount to its recipient and records the release.
+    fn execute_schedule(
+        env: &Env,
         bounty_id: u64,
-        amount: Option<i128>,
-        recipient: Option<Address>,
-        mode: RefundMode,
+        schedule: ReleaseSchedule,
+        released_by: Address,
+        release_type: ReleaseType,
     ) -> Result<(), Error> {
-        let start = env.ledger().timestamp();
+        Self::enter_guard(env, symbol_short!("release"));
+        Self::release_schedule_unguarded(env, bounty_id, schedule, released_by, release_type)?;
+        Self::exit_guard(env, symbol_short!("release"));
+        Ok(())
+    }
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            let caller = env.current_contract_address();
-            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
-            return Err(Error::ContractPaused);
+    /// `execute_schedule` without the reentrancy guard, for callers that
+    /// already hold it. Every check runs before anything is written, so an
+    /// `Err` leaves storage untouched.
+    fn release_schedule_unguarded(
+        env: &Env,
+        bounty_id: u64,
+        mut schedule: ReleaseSchedule,
+        released_by: Address,
+        release_type: ReleaseType,
+    ) -> Result<(), Error> {
+        if schedule.released {
+            return Err(Error::ScheduleAlreadyReleased);
         }
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            let caller = env.current_contract_address();
-            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyNotFound);
+        if Self::is_scope_paused(env, PauseScope::Schedule) {
+            return Err(Error::ContractPaused);
         }
 
-        // Get and verify escrow state
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        let caller = escrow.depositor.clone();
+            .ok_or(Error::BountyNotFound)?;
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyReleased
         {
             return Err(Error::FundsNotLocked);
         }
-
-        // Verify deadline has passed
-        let now = env.ledger().timestamp();
-        let is_before_deadline = now < escrow.deadline;
-
-        // Determine refund amount and recipient
-        let refund_amount: i128;
-        let refund_recipient: Address;
-
-        match mode {
-            RefundMode::Full => {
-                refund_amount = escrow.remaining_amount;
-                refund_recipient = escrow.depositor.clone();
-                if is_before_deadline {
-                    return Err(Error::DeadlineNotPassed);
-                }
-            }
-            RefundMode::Partial => {
-                refund_amount = amount.unwrap_or(escrow.remaining_amount);
-                refund_recipient = escrow.depositor.clone();
-                if is_before_deadline {
-                    return Err(Error::DeadlineNotPassed);
-                }
-            }
-            RefundMode::Custom => {
-                refund_amount = amount.ok_or(Error::InvalidAmount)?;
-                refund_recipient = recipient.ok_or(Error::InvalidAmount)?;
-
-                // Custom refunds before deadline require admin approval
-                if is_before_deadline {
-                    if !env
-                        .storage()
-                        .persistent()
-                        .has(&DataKey::RefundApproval(bounty_id))
-                    {
-                        return Err(Error::RefundNotApproved);
-                    }
-                    let approval: RefundApproval = env
-                        .storage()
-                        .persistent()
-                        .get(&DataKey::RefundApproval(bounty_id))
-                        .unwrap();
-
-                    // Verify approval matches request
-                    if approval.amount != refund_amount
-                        || approval.recipient != refund_recipient
-                        || approval.mode != mode
-                    {
-                        return Err(Error::RefundNotApproved);
-                    }
-
-                    // Clear approval after use
-                    env.storage()
-                        .persistent()
-                        .remove(&DataKey::RefundApproval(bounty_id));
-                }
-            }
-        }
-
-        // Validate amount
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+        if escrow.disputed {
+            return Err(Error::BountyDisputed);
         }
-
-        // Transfer funds back to depositor
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-
-        // Check contract balance
-        let contract_balance = client.balance(&env.current_contract_address());
-        if contract_balance < refund_amount {
+        if schedule.amount > escrow.remaining_amount {
             return Err(Error::InsufficientFunds);
         }
 
-        // Transfer funds
+        let token_addr = escrow.token.clone();
+        let client = token::Client::new(env, &token_addr);
         client.transfer(
             &env.current_contract_address(),
-            &refund_recipient,
-            &refund_amount,
+            &schedule.recipient,
+            &schedule.amount,
         );
 
-        // Update escrow state
-        escrow.remaining_amount -= refund_amount;
-
-        // Add to refund history
-        let refund_record = RefundRecord {
-            amount: refund_amount,
-            recipient: refund_recipient.clone(),
-            mode,
-            timestamp: env.ledger().timestamp(),
+        let timestamp = env.ledger().timestamp();
+        escrow.remaining_amount -= schedule.amount;
+        escrow.payout_history.push_back(PayoutRecord {
+            amount: schedule.amount,
+            recipient: schedule.recipient.clone(),
+            timestamp,
+            memo: None,
+        });
+        escrow.status = if escrow.remaining_amount == 0 {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::PartiallyReleased
         };
-        escrow.refund_history.push_back(refund_record);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
 
-        // Update status
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
-        } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
-        }
+        schedule.released = true;
+        schedule.released_at = Some(timestamp);
+        schedule.released_by = Some(released_by.clone());
+        env.storage().persistent().set(
+            &DataKey::ReleaseSchedule(bounty_id, schedule.schedule_id),
+            &schedule,
+        );
 
+        let mut history = Self::get_release_history(env.clone(), bounty_id);
+        history.push_back(ReleaseHistory {
+            schedule_id: schedule.schedule_id,
+            bounty_id,
+            amount: schedule.amount,
+            recipient: schedule.recipient.clone(),
+            released_at: timestamp,
+            released_by: released_by.clone(),
+            release_type,
+        });
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .set(&DataKey::ReleaseHistory(bounty_id), &history);
 
-        // Emit refund event
-        emit_funds_refunded(
-            &env,
-            FundsRefunded {
+        events::emit_schedule_released(
+            env,
+            events::ScheduleReleased {
                 bounty_id,
-                amount: refund_amount,
-                refund_to: refund_recipient,
-                timestamp: env.ledger().timestamp(),
-                refund_mode: mode,
-                remaining_amount: escrow.remaining_amount,
+                schedule_id: schedule.schedule_id,
+                amount: schedule.amount,
+                recipient: schedule.recipient,
+                released_at: timestamp,
+                released_by: released_by.clone(),
+                release_type,
             },
         );
-
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
-
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
-
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("refund"), duration);
+        Self::emit_lifecycle(
+            env,
+            bounty_id,
+            symbol_short!("release"),
+            released_by.clone(),
+            schedule.amount,
+        );
+        Self::record_status_change(env, bounty_id, escrow.status, released_by);
 
         Ok(())
     }
 
     // ========================================================================
-    // View Functions (Read-only)
+    // Milestone Functions
     // ========================================================================
 
-    /// Retrieves escrow information for a specific bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
-    ///
-    /// # Returns
-    /// * `Ok(Escrow)` - The complete escrow record
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    ///
-    /// # Gas Cost
-    /// Very Low - Single storage read
-    ///
-    /// # Example
-    /// ```rust
-    /// let escrow_info = escrow_client.get_escrow_info(&42)?;
-    /// println!("Amount: {}", escrow_info.amount);
-    /// println!("Status: {:?}", escrow_info.status);
-    /// println!("Deadline: {}", escrow_info.deadline);
-    /// ```
-    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-        Ok(env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap())
-    }
-
-    /// Returns the current token balance held by the contract.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    ///
-    /// # Returns
-    /// * `Ok(i128)` - Current contract token balance
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    ///
-    /// # Use Cases
-    /// - Monitoring total locked funds
-    /// - Verifying contract solvency
-    /// - Auditing and reconciliation
-    ///
-    /// # Gas Cost
-    /// Low - Token contract call
-    ///
-    /// # Example
-    /// ```rust
-    /// let balance = escrow_client.get_balance()?;
-    /// println!("Total locked: {} stroops", balance);
-    /// ```
-    pub fn get_balance(env: Env) -> Result<i128, Error> {
-        if !env.storage().instance().has(&DataKey::Token) {
-            return Err(Error::NotInitialized);
-        }
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        Ok(client.balance(&env.current_contract_address()))
-    }
-
-    /// Retrieves the refund history for a specific bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
+    /// Adds a milestone worth `amount` to a bounty (admin only).
     ///
     /// # Returns
-    /// * `Ok(Vec<RefundRecord>)` - The refund history
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    pub fn get_refund_history(env: Env, bounty_id: u64) -> Result<Vec<RefundRecord>, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
+    /// * `Ok(u32)` - Id of the new milestone
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't Locked or PartiallyReleased
+    /// * `Err(Error::InvalidAmount)` - `amount` isn't positive
+    /// * `Err(Error::InsufficientFunds)` - Unreleased milestones would exceed
+    ///   the remaining amount
+    pub fn add_milestone(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        description: String,
+    ) -> Result<u32, Error> {
+        Self::require_admin(&env)?;
+
         let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        Ok(escrow.refund_history)
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyReleased
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let mut milestones = Self::get_milestones(env.clone(), bounty_id);
+        let pending: i128 = milestones
+            .iter()
+            .filter(|milestone| !milestone.released)
+            .map(|milestone| milestone.amount)
+            .sum();
+        if pending + amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        let id = milestones.len() + 1;
+        milestones.push_back(Milestone {
+            id,
+            amount,
+            description,
+            approved: false,
+            released: false,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::Milestones(bounty_id), &milestones);
+
+        Ok(id)
     }
 
-    /// Retrieves the payout history for a specific bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
+    /// Approves a milestone for release (admin only).
     ///
     /// # Returns
-    /// * `Ok(Vec<PayoutRecord>)` - The payout history
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    pub fn get_payout_history(env: Env, bounty_id: u64) -> Result<Vec<PayoutRecord>, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+    /// * `Err(Error::MilestoneNotFound)` - No milestone with this id
+    /// * `Err(Error::AlreadyApproved)` - The milestone was already approved
+    pub fn approve_milestone(env: Env, bounty_id: u64, milestone_id: u32) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        let mut milestones = Self::get_milestones(env.clone(), bounty_id);
+        let index = Self::milestone_index(&milestones, milestone_id)?;
+        let mut milestone = milestones.get(index).unwrap();
+        if milestone.approved {
+            return Err(Error::AlreadyApproved);
         }
-        let escrow: Escrow = env
-            .storage()
+
+        milestone.approved = true;
+        milestones.set(index, milestone);
+        env.storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        Ok(escrow.payout_history)
+            .set(&DataKey::Milestones(bounty_id), &milestones);
+
+        Ok(())
     }
 
-    /// Gets refund eligibility information for a bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
+    /// Pays an approved milestone to `contributor` (admin only).
     ///
     /// # Returns
-    /// * `Ok((bool, bool, i128, Option<RefundApproval>))` - Tuple containing:
-    ///   - can_refund: Whether refund is possible
-    ///   - deadline_passed: Whether the deadline has passed
-    ///   - remaining: Remaining amount in escrow
-    ///   - approval: Optional refund approval if exists
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    pub fn get_refund_eligibility(
+    /// * `Err(Error::MilestoneNotFound)` - No milestone with this id
+    /// * `Err(Error::MilestoneNotApproved)` - The admin hasn't approved it yet
+    /// * `Err(Error::MilestoneAlreadyReleased)` - It was already paid out
+    /// * `Err(Error::InsufficientFunds)` - Less than its amount is left in escrow
+    ///
+    /// # Events
+    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp, remaining_amount }`
+    pub fn release_milestone(
         env: Env,
         bounty_id: u64,
-    ) -> Result<(bool, bool, i128, Option<RefundApproval>), Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        milestone_id: u32,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+
+        if Self::is_scope_paused(&env, PauseScope::Release) {
+            return Err(Error::ContractPaused);
         }
-        let escrow: Escrow = env
+        if contributor == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+        Self::ensure_allowed_recipient(&env, bounty_id, &contributor)?;
+        Self::ensure_acknowledged(&env, bounty_id, &contributor)?;
+
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        let now = env.ledger().timestamp();
-        let deadline_passed = now >= escrow.deadline;
+            .ok_or(Error::BountyNotFound)?;
 
-        let approval = if env
-            .storage()
-            .persistent()
-            .has(&DataKey::RefundApproval(bounty_id))
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyReleased
         {
-            Some(
-                env.storage()
-                    .persistent()
-                    .get(&DataKey::RefundApproval(bounty_id))
-                    .unwrap(),
-            )
-        } else {
-            None
-        };
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.disputed {
+            return Err(Error::BountyDisputed);
+        }
 
-        // can_refund is true if:
-        // 1. Status is Locked or PartiallyRefunded AND
-        // 2. (deadline has passed OR there's an approval)
-        let can_refund = (escrow.status == EscrowStatus::Locked
-            || escrow.status == EscrowStatus::PartiallyRefunded)
-            && (deadline_passed || approval.is_some());
+        let mut milestones = Self::get_milestones(env.clone(), bounty_id);
+        let index = Self::milestone_index(&milestones, milestone_id)?;
+        let mut milestone = milestones.get(index).unwrap();
+        if milestone.released {
+            return Err(Error::MilestoneAlreadyReleased);
+        }
+        if !milestone.approved {
+            return Err(Error::MilestoneNotApproved);
+        }
+        if milestone.amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
+        }
+        Self::consume_release_approval(&env, bounty_id, &contributor, milestone.amount)?;
 
-        Ok((
-            can_refund,
-            deadline_passed,
-            escrow.remaining_amount,
-            approval,
-        ))
-    }
+        Self::enter_guard(&env, symbol_short!("release"));
 
-    // ========================================================================
-    // Query Functions
-    // ========================================================================
+        let client = token::Client::new(&env, &escrow.token);
+        client.transfer(
+            &env.current_contract_address(),
+            &contributor,
+            &milestone.amount,
+        );
 
-    /// Query bounties with filtering and pagination.
-    ///
-    /// # Performance
-    /// This function iterates through the registry. For large datasets, use small `pagination.limit` values
-    /// to prevent gas limit errors. This is designed for off-chain indexing.
-    pub fn get_bounties(
-        env: Env,
-        filter: EscrowFilter,
-        pagination: Pagination,
-    ) -> Vec<(u64, Escrow)> {
-        let registry: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey::BountyRegistry)
-            .unwrap_or(vec![&env]);
+        let timestamp = env.ledger().timestamp();
+        escrow.remaining_amount -= milestone.amount;
+        escrow.payout_history.push_back(PayoutRecord {
+            amount: milestone.amount,
+            recipient: contributor.clone(),
+            timestamp,
+            memo: None,
+        });
+        escrow.status = if escrow.remaining_amount == 0 {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::PartiallyReleased
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
 
-        let mut result = vec![&env];
-        let mut count: u32 = 0;
-        let mut skipped: u64 = 0;
+        milestone.released = true;
+        let amount = milestone.amount;
+        milestones.set(index, milestone);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Milestones(bounty_id), &milestones);
 
-        for i in 0..registry.len() {
-            // Check pagination limit
-            if count >= pagination.limit {
-                break;
-            }
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                bounty_id,
+                amount,
+                recipient: contributor,
+                timestamp,
+                remaining_amount: escrow.remaining_amount,
+                memo: None,
+            },
+        );
+        Self::emit_lifecycle(
+            &env,
+            bounty_id,
+            symbol_short!("release"),
+            admin.clone(),
+            amount,
+        );
+        Self::record_status_change(&env, bounty_id, escrow.status, admin.clone());
 
-            let bounty_id = registry.get(i).unwrap();
+        Self::exit_guard(&env, symbol_short!("release"));
 
-            // Skip invalid IDs/missing data
-            if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-                continue;
-            }
+        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
 
-            let escrow: Escrow = env
-                .storage()
-                .persistent()
-                .get(&DataKey::Escrow(bounty_id))
-                .unwrap();
+        Ok(())
+    }
 
-            // Apply Filters
+    /// Get a bounty's milestones in creation order (view function)
+    pub fn get_milestones(env: Env, bounty_id: u64) -> Vec<Milestone> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Milestones(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
 
-            // Status filter
-            if let Some(status_val) = filter.status {
-                if (escrow.status as u32) != status_val {
-                    continue;
-                }
-            }
+    /// Position of `milestone_id` in `milestones` (internal helper)
+    fn milestone_index(milestones: &Vec<Milestone>, milestone_id: u32) -> Result<u32, Error> {
+        milestones
+            .iter()
+            .position(|milestone| milestone.id == milestone_id)
+            .map(|index| index as u32)
+            .ok_or(Error::MilestoneNotFound)
+    }
 
-            // Depositor filter
-            if let Some(depositor) = &filter.depositor {
-                if &escrow.depositor != depositor {
-                    continue;
-                }
-            }
+    // ========================================================================
+    // Role Management Functions
+    // ========================================================================
 
-            // Amount range filter
-            if let Some(min) = filter.min_amount {
-                if escrow.amount < min {
-                    continue;
-                }
-            }
-            if let Some(max) = filter.max_amount {
-                if escrow.amount > max {
-                    continue;
-                }
-            }
+    /// Grants `role` to `address` (admin only).
+    pub fn grant_role(env: Env, role: roles::Role, address: Address) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        roles::grant(&env, admin, role, address);
+        Ok(())
+    }
 
-            // Date range filter (using deadline)
-            if let Some(start) = filter.start_time {
-                if escrow.deadline < start {
-                    continue;
-                }
-            }
-            if let Some(end) = filter.end_time {
-                if escrow.deadline > end {
-                    continue;
-                }
-            }
+    /// Revokes `role` from `address` (admin only).
+    pub fn revoke_role(env: Env, role: roles::Role, address: Address) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        roles::revoke(&env, admin, role, address);
+        Ok(())
+    }
 
-            // Apply Pagination Skip
-            if skipped < pagination.start_index {
-                skipped += 1;
-                continue;
-            }
+    /// Returns whether `address` holds `role`. The admin holds every role.
+    pub fn has_role(env: Env, role: roles::Role, address: Address) -> bool {
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        admin == Some(address.clone()) || roles::is_member(&env, role, address)
+    }
 
-            // Add to result
-            result.push_back((bounty_id, escrow));
-            count += 1;
+    /// Checks that `caller` holds `role` and requires its authorization
+    /// (internal helper)
+    fn require_role(env: &Env, role: roles::Role, caller: &Address) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
-
-        result
+        if !Self::has_role(env.clone(), role, caller.clone()) {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+        Ok(())
     }
 
-    /// Get aggregate statistics for the contract.
-    ///
-    /// # Performance
-    /// This function iterates over ALL bounties. It is O(N) and may fail on-chain if N is large.
-    /// Use primarily for off-chain monitoring/indexing.
-    pub fn get_stats(env: Env) -> EscrowStats {
-        let registry: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey::BountyRegistry)
-            .unwrap_or(vec![&env]);
+    // ========================================================================
+    // Anti-Abuse Administrative Functions
+    // ========================================================================
 
-        let mut total_locked: i128 = 0;
-        let mut total_released: i128 = 0;
-        let mut total_refunded: i128 = 0;
+    /// Sets the rate limit for a single operation (e.g. `lock`, `release`).
+    /// Operations without their own config use the global default.
+    /// A nonzero `max_cooldown_period` enables backoff: each consecutive
+    /// window that hits `max_operations` doubles the cooldown, up to that cap.
+    /// Only the anti-abuse admin (the contract admin set at `init`) can call this.
+    pub fn set_config_for_operation(
+        env: Env,
+        operation: Symbol,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+        max_cooldown_period: u64,
+    ) -> Result<(), Error> {
+        let admin = anti_abuse::get_admin(&env).ok_or(Error::NotInitialized)?;
+        admin.require_auth();
 
-        for i in 0..registry.len() {
-            let bounty_id = registry.get(i).unwrap();
-            if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(bounty_id))
-                    .unwrap();
+        anti_abuse::set_config_for_operation(
+            &env,
+            operation,
+            anti_abuse::AntiAbuseConfig {
+                window_size,
+                max_operations,
+                cooldown_period,
+                max_cooldown_period,
+            },
+        );
 
-                match escrow.status {
-                    EscrowStatus::Locked => {
-                        total_locked += escrow.remaining_amount;
-                    }
-                    EscrowStatus::Released => {
-                        total_released += escrow.amount;
-                    }
-                    EscrowStatus::Refunded => {
-                        for record in escrow.refund_history.iter() {
-                            total_refunded += record.amount;
-                        }
-                    }
-                    EscrowStatus::PartiallyRefunded => {
-                        total_locked += escrow.remaining_amount;
-                        for record in escrow.refund_history.iter() {
-                            total_refunded += record.amount;
-                        }
-                    }
-                }
-            }
-        }
+        Ok(())
+    }
 
-        EscrowStats {
-            total_bounties: registry.len() as u64,
-            total_locked_amount: total_locked,
-            total_released_amount: total_released,
-            total_refunded_amount: total_refunded,
-        }
+    /// Adds or removes an address from the whitelist.
+    /// Whitelisted addresses bypass rate limiting entirely.
+    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) -> Result<(), Error> {
+        let admin = anti_abuse::get_admin(&env).ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        anti_abuse::set_whitelist(&env, address, whitelisted);
+        Ok(())
     }
 
-    /// Batch lock funds for multiple bounties in a single transaction.
-    /// This improves gas efficiency by reducing transaction overhead.
-    ///
-    /// # Arguments
-    /// * `items` - Vector of LockFundsItem containing bounty_id, depositor, amount, and deadline
+    /// Adds or removes every address in `addresses` from the whitelist in
+    /// one call, e.g. when onboarding or rotating backend keys.
     ///
     /// # Returns
-    /// Number of successfully locked bounties
+    /// The number of addresses whose whitelist status changed
     ///
     /// # Errors
-    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
-    /// * BountyExists - if any bounty_id already exists
-    /// * NotInitialized - if contract is not initialized
-    ///
-    /// # Note
-    /// This operation is atomic - if any item fails, the entire transaction reverts.
-    pub fn batch_lock_funds(env: Env, items: Vec<LockFundsItem>) -> Result<u32, Error> {
-        // Validate batch size
-        let batch_size = items.len();
-        if batch_size == 0 {
-            return Err(Error::InvalidBatchSize);
-        }
-        if batch_size > MAX_BATCH_SIZE {
-            return Err(Error::InvalidBatchSize);
-        }
-
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            return Err(Error::ContractPaused);
-        }
+    /// * NotInitialized - if no anti-abuse admin is set
+    /// * InvalidBatchSize - if `addresses` is empty or exceeds `get_max_batch_size`
+    pub fn set_whitelist_batch(
+        env: Env,
+        addresses: Vec<Address>,
+        whitelisted: bool,
+    ) -> Result<u32, Error> {
+        let admin = anti_abuse::get_admin(&env).ok_or(Error::NotInitialized)?;
+        admin.require_auth();
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        if addresses.is_empty() || addresses.len() > Self::max_batch_size(&env) {
+            return Err(Error::InvalidBatchSize);
         }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
-        let timestamp = env.ledger().timestamp();
-
-        // Validate all items before processing (all-or-nothing approach)
-        for item in items.iter() {
-            // Check if bounty already exists
-            if env
-                .storage()
-                .persistent()
-                .has(&DataKey::Escrow(item.bounty_id))
-            {
-                return Err(Error::BountyExists);
-            }
+        Ok(anti_abuse::set_whitelist_batch(
+            &env,
+            admin,
+            addresses,
+            whitelisted,
+        ))
+    }
 
-            // Validate amount
-            if item.amount <= 0 {
-                return Err(Error::InvalidAmount);
-            }
+    /// Checks if an address is whitelisted.
+    pub fn is_whitelisted(env: Env, address: Address) -> bool {
+        anti_abuse::is_whitelisted(&env, address)
+    }
 
-            // Check for duplicate bounty_ids in the batch
-            let mut count = 0u32;
-            for other_item in items.iter() {
-                if other_item.bounty_id == item.bounty_id {
-                    count += 1;
-                }
-            }
-            if count > 1 {
-                return Err(Error::DuplicateBountyId);
-            }
-        }
+    /// Bans or unbans an address. Banned addresses are rejected by every
+    /// rate-limited operation, regardless of the whitelist.
+    /// Only the anti-abuse admin can call this.
+    pub fn set_blacklist(env: Env, address: Address, banned: bool) -> Result<(), Error> {
+        let admin = anti_abuse::get_admin(&env).ok_or(Error::NotInitialized)?;
+        admin.require_auth();
 
-        // Collect unique depositors and require auth once for each
-        // This prevents "frame is already authorized" errors when same depositor appears multiple times
-        let mut seen_depositors: Vec<Address> = Vec::new(&env);
-        for item in items.iter() {
-            let mut found = false;
-            for seen in seen_depositors.iter() {
-                if seen.clone() == item.depositor {
-                    found = true;
-                    break;
-                }
-            }
-            if !found {
-                seen_depositors.push_back(item.depositor.clone());
-                item.depositor.require_auth();
-            }
-        }
+        anti_abuse::set_blacklist(&env, admin, address, banned);
+        Ok(())
+    }
 
-        // Process all items (atomic - all succeed or all fail)
-        let mut locked_count = 0u32;
-        for item in items.iter() {
-            // Transfer funds from depositor to contract
-            client.transfer(&item.depositor, &contract_address, &item.amount);
+    /// Checks if an address is blacklisted.
+    pub fn is_blacklisted(env: Env, address: Address) -> bool {
+        anti_abuse::is_blacklisted(&env, address)
+    }
 
-            // Create escrow record
-            let escrow = Escrow {
-                depositor: item.depositor.clone(),
-                amount: item.amount,
-                status: EscrowStatus::Locked,
-                deadline: item.deadline,
-                refund_history: vec![&env],
-                payout_history: vec![&env],
-                remaining_amount: item.amount,
-            };
-            // Store escrow
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+    /// Gets the rate limit configuration that applies to an operation.
+    pub fn get_rate_limit_config(env: Env, operation: Symbol) -> anti_abuse::AntiAbuseConfig {
+        anti_abuse::get_config_for_operation(&env, operation)
+    }
 
-            // Emit individual event for each locked bounty
-            emit_funds_locked(
-                &env,
-                FundsLocked {
-                    bounty_id: item.bounty_id,
-                    amount: item.amount,
-                    depositor: item.depositor.clone(),
-                    deadline: item.deadline,
-                },
-            );
+    /// Gets the rate limit state of an address for an operation.
+    /// Returns an all-zero state if the address hasn't performed it yet.
+    pub fn get_rate_limit_state(
+        env: Env,
+        address: Address,
+        operation: Symbol,
+    ) -> anti_abuse::AddressState {
+        anti_abuse::get_state(&env, address, operation)
+    }
 
-            locked_count += 1;
-        }
+    /// Clears the rate limit state of an address for every rate-limited
+    /// operation, unblocking it immediately (anti-abuse admin only).
+    pub fn clear_rate_limit_state(env: Env, address: Address) -> Result<(), Error> {
+        let admin = anti_abuse::get_admin(&env).ok_or(Error::Unauthorized)?;
+        admin.require_auth();
 
-        // Emit batch event
-        emit_batch_funds_locked(
+        anti_abuse::clear_state(
             &env,
-            BatchFundsLocked {
-                count: locked_count,
-                total_amount: items.iter().map(|i| i.amount).sum(),
-                timestamp,
-            },
+            admin,
+            address,
+            &[
+                symbol_short!("init"),
+                symbol_short!("lock"),
+                symbol_short!("release"),
+            ],
         );
 
-        Ok(locked_count)
+        Ok(())
     }
 
-    /// Batch release funds to multiple contributors in a single transaction.
-    /// This improves gas efficiency by reducing transaction overhead.
-    ///
-    /// # Arguments
-    /// * `items` - Vector of ReleaseFundsItem containing bounty_id and contributor address
-    ///
-    /// # Returns
-    /// Number of successfully released bounties
+    /// Seconds until the address can perform the operation again.
+    /// Zero means the next call won't be rate limited.
+    pub fn seconds_until_allowed(env: Env, address: Address, operation: Symbol) -> u64 {
+        anti_abuse::seconds_until_allowed(&env, address, operation)
+    }
+
+    // ========================================================================
+    // Monitoring & Analytics Functions
+    // ========================================================================
+
+    /// Health check - returns contract health status
     ///
-    /// # Errors
-    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
-    /// * BountyNotFound - if any bounty_id doesn't exist
-    /// * FundsNotLocked - if any bounty is not in Locked status
-    /// * Unauthorized - if caller is not admin
+    /// The contract is unhealthy while paused, when the error rate is above
+    /// `get_max_error_rate`, or when it holds less of any token than the
+    /// escrows still have locked.
     ///
-    /// # Note
-    /// This operation is atomic - if any item fails, the entire transaction reverts.
-    pub fn batch_release_funds(env: Env, items: Vec<ReleaseFundsItem>) -> Result<u32, Error> {
-        // Validate batch size
-        let batch_size = items.len();
-        if batch_size == 0 {
-            return Err(Error::InvalidBatchSize);
-        }
-        if batch_size > MAX_BATCH_SIZE {
-            return Err(Error::InvalidBatchSize);
-        }
+    /// # Performance
+    /// The solvency check iterates over ALL bounties, like `get_stats`.
+    pub fn health_check(env: Env) -> monitoring::HealthStatus {
+        let is_paused = Self::is_paused_internal(&env);
+        let is_solvent = Self::is_solvent(&env);
+        let max_error_rate = Self::get_max_error_rate(env.clone());
+        monitoring::health_check(&env, is_paused, is_solvent, max_error_rate)
+    }
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            return Err(Error::ContractPaused);
-        }
+    /// Sets the error rate, in basis points, above which `health_check`
+    /// reports the contract unhealthy (admin only). Anything at or above
+    /// 10_000 disables the check.
+    pub fn set_max_error_rate(env: Env, max_error_rate: u32) -> Result<(), Error> {
+        Self::require_admin(&env)?;
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxErrorRate, &max_error_rate);
+        Ok(())
+    }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    /// Error rate threshold used by `health_check`, in basis points
+    pub fn get_max_error_rate(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxErrorRate)
+            .unwrap_or(DEFAULT_MAX_ERROR_RATE)
+    }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
-        let timestamp = env.ledger().timestamp();
+    /// Checks that the contract holds at least what its unfinished escrows
+    /// still owe in the default token.
+    ///
+    /// # Returns
+    /// * `(solvent, owed, balance)` - `owed` sums `remaining_amount` over
+    ///   Locked and partially paid bounties; `balance` is the contract's
+    ///   actual token balance
+    ///
+    /// # Performance
+    /// Iterates over ALL bounties, like `get_stats`.
+    pub fn check_solvency(env: Env) -> Result<(bool, i128, i128), Error> {
+        let token_addr = Self::get_token(env.clone())?;
+        let owed = Self::locked_totals(&env)
+            .get(token_addr.clone())
+            .unwrap_or(0);
+        let balance =
+            token::Client::new(&env, &token_addr).balance(&env.current_contract_address());
+        Ok((balance >= owed, owed, balance))
+    }
 
-        // Validate all items before processing (all-or-nothing approach)
-        let mut total_amount: i128 = 0;
-        for item in items.iter() {
-            // Check if bounty exists
-            if !env
-                .storage()
-                .persistent()
-                .has(&DataKey::Escrow(item.bounty_id))
-            {
-                return Err(Error::BountyNotFound);
-            }
+    /// True if the contract holds at least the remaining amount of every
+    /// unfinished escrow, token by token
+    fn is_solvent(env: &Env) -> bool {
+        let contract = env.current_contract_address();
+        Self::locked_totals(env).iter().all(|(token_addr, total)| {
+            token::Client::new(env, &token_addr).balance(&contract) >= total
+        })
+    }
 
-            let escrow: Escrow = env
+    /// Sums `remaining_amount` per token over the escrows that aren't in a
+    /// terminal status
+    fn locked_totals(env: &Env) -> Map<Address, i128> {
+        let registry: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::BountyRegistry)
+            .unwrap_or(vec![env]);
+
+        let mut locked: Map<Address, i128> = Map::new(env);
+        for bounty_id in registry.iter() {
+            let Some(escrow) = env
                 .storage()
                 .persistent()
-                .get(&DataKey::Escrow(item.bounty_id))
-                .unwrap();
-
-            // Check if funds are locked
-            if escrow.status != EscrowStatus::Locked {
-                return Err(Error::FundsNotLocked);
+                .get::<_, Escrow>(&DataKey::Escrow(bounty_id))
+            else {
+                continue;
+            };
+            if matches!(
+                escrow.status,
+                EscrowStatus::Locked
+                    | EscrowStatus::PartiallyReleased
+                    | EscrowStatus::PartiallyRefunded
+            ) {
+                let total = locked.get(escrow.token.clone()).unwrap_or(0);
+                locked.set(escrow.token, total + escrow.remaining_amount);
             }
+        }
+        locked
+    }
 
-            // Check for duplicate bounty_ids in the batch
-            let mut count = 0u32;
-            for other_item in items.iter() {
-                if other_item.bounty_id == item.bounty_id {
-                    count += 1;
-                }
-            }
-            if count > 1 {
-                return Err(Error::DuplicateBountyId);
-            }
+    /// Get analytics - returns usage analytics
+    pub fn get_analytics(env: Env) -> monitoring::Analytics {
+        monitoring::get_analytics(&env)
+    }
 
-            total_amount = total_amount
-                .checked_add(escrow.amount)
-                .ok_or(Error::InvalidAmount)?;
-        }
+    /// Get state snapshot - returns current state
+    pub fn get_state_snapshot(env: Env) -> monitoring::StateSnapshot {
+        monitoring::get_state_snapshot(&env)
+    }
 
-        // Process all items (atomic - all succeed or all fail)
-        let mut released_count = 0u32;
-        for item in items.iter() {
-            let mut escrow: Escrow = env
-                .storage()
-                .persistent()
-                .get(&DataKey::Escrow(item.bounty_id))
-                .unwrap();
+    /// Counts by `Error` code of the failures successful calls skipped
+    /// over, such as schedules `batch_release_due_schedules` couldn't
+    /// release. Calls that return an error are rolled back and don't appear.
+    pub fn get_error_breakdown(env: Env) -> Map<u32, u64> {
+        monitoring::get_error_breakdown(&env)
+    }
 
-            // Transfer funds to contributor
-            client.transfer(&contract_address, &item.contributor, &escrow.amount);
+    /// Get performance stats for a function
+    pub fn get_performance_stats(env: Env, function_name: Symbol) -> monitoring::PerformanceStats {
+        monitoring::get_performance_stats(&env, function_name)
+    }
 
-            // Update escrow status
-            escrow.status = EscrowStatus::Released;
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+    /// Sets how much the contract emits (admin only). Counters and stats
+    /// keep updating whatever the verbosity.
+    pub fn set_event_verbosity(env: Env, verbosity: EventVerbosity) -> Result<(), Error> {
+        Self::require_admin(&env)?;
 
-            // Emit individual event for each released bounty
-            emit_funds_released(
-                &env,
-                FundsReleased {
-                    bounty_id: item.bounty_id,
-                    amount: escrow.amount,
-                    recipient: item.contributor.clone(),
-                    timestamp,
-                    remaining_amount: escrow.remaining_amount,
-                },
-            );
+        env.storage()
+            .instance()
+            .set(&DataKey::EventVerbosity, &verbosity);
+        Ok(())
+    }
 
-            released_count += 1;
+    /// Get the event verbosity (view function)
+    pub fn get_event_verbosity(env: Env) -> EventVerbosity {
+        events::event_verbosity(&env)
+    }
+
+    /// Reset operation, user and error counters, the error breakdown and the per-function
+    /// performance stats of every tracked entrypoint (admin only)
+    pub fn reset_analytics(env: Env) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
-        // Emit batch event
-        emit_batch_funds_released(
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        monitoring::reset_analytics(
             &env,
-            BatchFundsReleased {
-                count: released_count,
-                total_amount,
-                timestamp,
-            },
+            admin,
+            &[
+                symbol_short!("init"),
+                symbol_short!("lock"),
+                symbol_short!("release"),
+                symbol_short!("refund"),
+                symbol_short!("settle"),
+            ],
         );
 
-        Ok(released_count)
+        Ok(())
     }
 }
 
@@ -1,11 +1,15 @@
 #![cfg(test)]
 
 use soroban_sdk::{
+    symbol_short,
     testutils::{Address as _, Events, Ledger},
-    token, vec, Address, Env,
+    token, vec, Address, Env, Symbol, TryFromVal, Vec,
 };
 
-use crate::{BountyEscrowContract, BountyEscrowContractClient};
+use crate::{
+    events::{BountyClosed, FundsReleased, LifecycleEvent},
+    BountyEscrowContract, BountyEscrowContractClient, EscrowStatus, EventVerbosity, RefundMode,
+};
 
 fn create_test_env() -> (Env, BountyEscrowContractClient<'static>, Address) {
     let env = Env::default();
@@ -239,13 +243,14 @@ fn test_lock_fund() {
     // Get all events emitted
     let events = env.events().all();
 
-    // Verify the event was emitted (5 original events + 4 monitoring events from init & lock_funds)
-    assert_eq!(events.len(), 9);
+    // Verify the event was emitted (5 original events + 4 monitoring events from init & lock_funds
+    // + 1 lifecycle envelope)
+    assert_eq!(events.len(), 10);
 }
 
 #[test]
 fn test_release_fund() {
-    let (env, client, _contract_id) = create_test_env();
+    let (env, client, contract_id) = create_test_env();
 
     let admin = Address::generate(&env);
     // let token = Address::generate(&env);
@@ -268,13 +273,45 @@ fn test_release_fund() {
 
     client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
 
-    client.release_funds(&bounty_id, &contributor, &None::<i128>);
+    client.release_funds(&admin, &bounty_id, &contributor, &None::<i128>);
 
     // Get all events emitted
     let events = env.events().all();
 
-    // Verify the event was emitted (7 original events + 6 monitoring events from init, lock_funds & release_funds)
-    assert_eq!(events.len(), 13);
+    // Verify the event was emitted (7 original events + 6 monitoring events from init, lock_funds & release_funds
+    // + 2 lifecycle envelopes + 1 bounty closed)
+    assert_eq!(events.len(), 16);
+
+    let released = funds_released_events(&env, &contract_id);
+    assert_eq!(released.len(), 1);
+    assert_eq!(released.get(0).unwrap().amount, amount);
+    assert_eq!(released.get(0).unwrap().remaining_amount, 0);
+}
+
+#[test]
+fn test_partial_release_event_remaining_amount() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+
+    client.init(&admin, &token);
+    token_admin_client.mint(&depositor, &1000);
+    client.lock_funds(&depositor, &1, &1000, &100);
+
+    client.release_funds(&admin, &1, &contributor, &Some(300));
+    client.release_funds(&admin, &1, &contributor, &None::<i128>);
+
+    let released = funds_released_events(&env, &contract_id);
+    assert_eq!(released.len(), 2);
+    assert_eq!(released.get(0).unwrap().amount, 300);
+    assert_eq!(released.get(0).unwrap().remaining_amount, 700);
+    assert_eq!(released.get(1).unwrap().amount, 700);
+    assert_eq!(released.get(1).unwrap().remaining_amount, 0);
 }
 
 #[test]
@@ -407,7 +444,7 @@ fn test_batch_release_funds() {
     });
 
     // Execute batch release
-    let released_count = client.batch_release_funds(&items);
+    let released_count = client.batch_release_funds(&admin, &items);
     assert_eq!(released_count, 2);
 
     // Verify funds were released
@@ -526,7 +563,7 @@ fn test_batch_lock_event_emission() {
 
 #[test]
 fn test_batch_release_event_emission() {
-    let (env, client, _contract_id) = create_test_env();
+    let (env, client, contract_id) = create_test_env();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
@@ -556,11 +593,212 @@ fn test_batch_release_event_emission() {
         contributor: contributor2.clone(),
     });
 
-    client.batch_release_funds(&items);
+    client.batch_release_funds(&admin, &items);
 
     // Verify events were emitted
     let events = env.events().all();
     assert!(events.len() > initial_event_count);
+
+    let released = funds_released_events(&env, &contract_id);
+    assert_eq!(released.len(), 2);
+    for event in released.iter() {
+        assert_eq!(event.remaining_amount, 0);
+    }
+    assert_eq!(client.get_escrow_info(&1).remaining_amount, 0);
+}
+
+fn funds_released_events(env: &Env, contract_id: &Address) -> Vec<FundsReleased> {
+    let mut result = Vec::new(env);
+    for (contract, topics, data) in env.events().all().iter() {
+        if &contract != contract_id {
+            continue;
+        }
+        let topic = Symbol::try_from_val(env, &topics.get(0).unwrap());
+        if topic == Ok(symbol_short!("f_rel")) {
+            result.push_back(FundsReleased::try_from_val(env, &data).unwrap());
+        }
+    }
+    result
+}
+
+fn lifecycle_events(env: &Env, contract_id: &Address) -> Vec<LifecycleEvent> {
+    let mut result = Vec::new(env);
+    for (contract, topics, data) in env.events().all().iter() {
+        if &contract != contract_id {
+            continue;
+        }
+        let topic = Symbol::try_from_val(env, &topics.get(0).unwrap());
+        if topic == Ok(symbol_short!("lifecycle")) {
+            result.push_back(LifecycleEvent::try_from_val(env, &data).unwrap());
+        }
+    }
+    result
+}
+
+#[test]
+fn test_lifecycle_envelope_emission() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+
+    client.init(&admin, &token);
+    token_admin_client.mint(&depositor, &5000);
+    env.ledger().set_timestamp(10);
+
+    client.lock_funds(&depositor, &1, &1000, &100);
+
+    env.ledger().set_timestamp(20);
+    client.release_funds(&admin, &1, &contributor, &Some(400));
+
+    // Past the depositor's lock cooldown
+    env.ledger().set_timestamp(80);
+    client.lock_funds(&depositor, &2, &500, &100);
+    env.ledger().set_timestamp(101);
+    client.refund(&2, &None::<i128>, &None::<Address>, &RefundMode::Full);
+
+    let envelopes = lifecycle_events(&env, &contract_id);
+    assert_eq!(
+        envelopes,
+        vec![
+            &env,
+            LifecycleEvent {
+                bounty_id: 1,
+                event_type: symbol_short!("lock"),
+                actor: depositor.clone(),
+                amount: 1000,
+                timestamp: 10,
+                seq: 0,
+            },
+            LifecycleEvent {
+                bounty_id: 1,
+                event_type: symbol_short!("release"),
+                actor: admin.clone(),
+                amount: 400,
+                timestamp: 20,
+                seq: 1,
+            },
+            LifecycleEvent {
+                bounty_id: 2,
+                event_type: symbol_short!("lock"),
+                actor: depositor.clone(),
+                amount: 500,
+                timestamp: 80,
+                seq: 2,
+            },
+            LifecycleEvent {
+                bounty_id: 2,
+                event_type: symbol_short!("refund"),
+                actor: depositor.clone(),
+                amount: 500,
+                timestamp: 101,
+                seq: 3,
+            },
+        ]
+    );
+}
+
+fn bounty_closed_events(env: &Env, contract_id: &Address) -> Vec<BountyClosed> {
+    let mut result = Vec::new(env);
+    for (contract, topics, data) in env.events().all().iter() {
+        if &contract != contract_id {
+            continue;
+        }
+        let topic = Symbol::try_from_val(env, &topics.get(0).unwrap());
+        if topic == Ok(symbol_short!("closed")) {
+            result.push_back(BountyClosed::try_from_val(env, &data).unwrap());
+        }
+    }
+    result
+}
+
+#[test]
+fn test_bounty_closed_only_on_terminal_transition() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+
+    client.init(&admin, &token);
+    token_admin_client.mint(&depositor, &5000);
+    env.ledger().set_timestamp(10);
+
+    // Partial release leaves the bounty open
+    client.lock_funds(&depositor, &1, &1000, &100);
+    client.release_funds(&admin, &1, &contributor, &Some(400));
+    assert_eq!(bounty_closed_events(&env, &contract_id).len(), 0);
+
+    // Past the admin's release cooldown
+    env.ledger().set_timestamp(70);
+    client.release_funds(&admin, &1, &contributor, &None::<i128>);
+    assert_eq!(
+        bounty_closed_events(&env, &contract_id),
+        vec![
+            &env,
+            BountyClosed {
+                bounty_id: 1,
+                final_status: EscrowStatus::Released,
+                timestamp: 70,
+            },
+        ]
+    );
+
+    // Same for partial refunds: only the last one closes the bounty
+    env.ledger().set_timestamp(80);
+    client.lock_funds(&depositor, &2, &500, &100);
+    env.ledger().set_timestamp(101);
+    client.refund(&2, &Some(200), &None::<Address>, &RefundMode::Partial);
+    assert_eq!(bounty_closed_events(&env, &contract_id).len(), 1);
+    client.refund(&2, &Some(300), &None::<Address>, &RefundMode::Partial);
+
+    let closed = bounty_closed_events(&env, &contract_id);
+    assert_eq!(closed.len(), 2);
+    assert_eq!(
+        closed.get(1).unwrap(),
+        BountyClosed {
+            bounty_id: 2,
+            final_status: EscrowStatus::Refunded,
+            timestamp: 101,
+        }
+    );
+}
+
+#[test]
+fn test_domain_only_verbosity_skips_metric_events() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+
+    client.init(&admin, &token);
+    token_admin_client.mint(&depositor, &1000);
+    assert_eq!(client.get_event_verbosity(), EventVerbosity::Full);
+
+    client.set_event_verbosity(&EventVerbosity::DomainOnly);
+    let before = env.events().all().len();
+    client.lock_funds(&depositor, &1, &1000, &100);
+
+    let mut topics_seen = Vec::new(&env);
+    let events = env.events().all();
+    for i in before..events.len() {
+        let (contract, topics, _) = events.get(i).unwrap();
+        if contract == contract_id {
+            topics_seen.push_back(Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap());
+        }
+    }
+    assert!(topics_seen.contains(symbol_short!("f_lock")));
+    assert!(!topics_seen.contains(symbol_short!("metric")));
 }
 
 // ============================================================================
@@ -596,11 +834,11 @@ fn test_complete_bounty_workflow_lock_release() {
     assert_eq!(escrow.status, crate::EscrowStatus::Locked);
 
     // 5. Verify contract balance
-    let contract_balance = client.get_balance();
+    let contract_balance = client.get_balance(&None);
     assert_eq!(contract_balance, amount);
 
     // 6. Release funds to contributor
-    client.release_funds(&bounty_id, &contributor, &None::<i128>);
+    client.release_funds(&admin, &bounty_id, &contributor, &None::<i128>);
 
     // 7. Verify funds released
     let escrow_after = client.get_escrow_info(&bounty_id);
@@ -672,20 +910,20 @@ fn test_pause_functionality() {
     assert!(!client.is_paused());
 
     // Pause contract
-    client.pause();
+    client.pause(&admin);
     assert!(client.is_paused());
 
     // Unpause contract
-    client.unpause();
+    client.unpause(&admin);
     assert!(!client.is_paused());
 
     // Pause again for emergency test
-    client.pause();
+    client.pause(&admin);
     assert!(client.is_paused());
 
     // Unpause to verify idempotent
-    client.unpause();
-    client.unpause(); // Call again - should not error
+    client.unpause(&admin);
+    client.unpause(&admin); // Call again - should not error
     assert!(!client.is_paused());
 }
 
@@ -703,7 +941,7 @@ fn test_emergency_withdraw() {
     client.init(&admin, &token_address);
 
     // Pause contract
-    client.pause();
+    client.pause(&admin);
     assert!(client.is_paused());
 
     // Call emergency_withdraw (it will fail gracefully if no funds)
@@ -1,8 +1,12 @@
 extern crate std;
 use crate::{
-    BountyEscrowContract, BountyEscrowContractClient, EscrowFilter, EscrowStatus, Pagination,
+    BountyEscrowContract, BountyEscrowContractClient, Error, EscrowFilter, EscrowStatus,
+    LockFundsItem, Pagination, RefundMode, SortBy,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
 };
-use soroban_sdk::{testutils::Address as _, token, Address, Env};
 
 fn create_token_contract<'a>(
     e: &'a Env,
@@ -66,7 +70,7 @@ fn test_get_bounties_filtering() {
 
     // Filter by Depositor 1
     let filter_dep1 = EscrowFilter {
-        status: None,
+        statuses: vec![&env],
         depositor: Some(depositor1.clone()),
         min_amount: None,
         max_amount: None,
@@ -76,6 +80,8 @@ fn test_get_bounties_filtering() {
     let bounds = Pagination {
         start_index: 0,
         limit: 10,
+        sort_by: SortBy::BountyId,
+        descending: false,
     };
     let bounties_dep1 = client.get_bounties(&filter_dep1, &bounds);
     assert_eq!(bounties_dep1.len(), 2);
@@ -84,7 +90,7 @@ fn test_get_bounties_filtering() {
 
     // Filter by Min Amount 250
     let filter_amt = EscrowFilter {
-        status: None,
+        statuses: vec![&env],
         depositor: None,
         min_amount: Some(250),
         max_amount: None,
@@ -97,7 +103,7 @@ fn test_get_bounties_filtering() {
 
     // Filter by Time (Deadline > 1500)
     let filter_time = EscrowFilter {
-        status: None,
+        statuses: vec![&env],
         depositor: None,
         min_amount: None,
         max_amount: None,
@@ -109,7 +115,7 @@ fn test_get_bounties_filtering() {
 
     // Filter by Status (Locked is default)
     let filter_status = EscrowFilter {
-        status: Some(EscrowStatus::Locked as u32),
+        statuses: vec![&env, EscrowStatus::Locked as u32],
         depositor: None,
         min_amount: None,
         max_amount: None,
@@ -123,7 +129,7 @@ fn test_get_bounties_filtering() {
 #[test]
 fn test_get_stats() {
     let env = Env::default();
-    let (client, _admin, _token, _token_client, token_admin) = create_test_env(&env);
+    let (client, admin, _token, _token_client, token_admin) = create_test_env(&env);
     let depositor = Address::generate(&env);
     token_admin.mint(&depositor, &10000);
 
@@ -138,7 +144,7 @@ fn test_get_stats() {
     assert_eq!(stats.total_released_amount, 0);
 
     // Release one
-    client.release_funds(&1, &Address::generate(&env));
+    client.release_funds(&admin, &1, &Address::generate(&env), &None::<i128>);
 
     let stats_after = client.get_stats();
     assert_eq!(stats_after.total_locked_amount, 200);
@@ -159,7 +165,7 @@ fn test_pagination() {
     }
 
     let filter_none = EscrowFilter {
-        status: None,
+        statuses: vec![&env],
         depositor: None,
         min_amount: None,
         max_amount: None,
@@ -173,6 +179,8 @@ fn test_pagination() {
         &Pagination {
             start_index: 0,
             limit: 2,
+            sort_by: SortBy::BountyId,
+            descending: false,
         },
     );
     assert_eq!(page1.len(), 2);
@@ -185,6 +193,8 @@ fn test_pagination() {
         &Pagination {
             start_index: 2,
             limit: 2,
+            sort_by: SortBy::BountyId,
+            descending: false,
         },
     );
     assert_eq!(page2.len(), 2);
@@ -197,6 +207,8 @@ fn test_pagination() {
         &Pagination {
             start_index: 4,
             limit: 2,
+            sort_by: SortBy::BountyId,
+            descending: false,
         },
     );
     assert_eq!(page3.len(), 1);
@@ -219,7 +231,7 @@ fn test_large_dataset_pagination() {
 
     // Query middle page (items 4-6)
     let filter_none = EscrowFilter {
-        status: None,
+        statuses: vec![&env],
         depositor: None,
         min_amount: None,
         max_amount: None,
@@ -232,6 +244,8 @@ fn test_large_dataset_pagination() {
         &Pagination {
             start_index: 3,
             limit: 3,
+            sort_by: SortBy::BountyId,
+            descending: false,
         },
     );
     assert_eq!(page.len(), 3);
@@ -244,6 +258,8 @@ fn test_large_dataset_pagination() {
         &Pagination {
             start_index: 8,
             limit: 5,
+            sort_by: SortBy::BountyId,
+            descending: false,
         },
     );
     assert_eq!(last_page.len(), 2); // 9, 10
@@ -254,3 +270,421 @@ fn test_large_dataset_pagination() {
     assert_eq!(stats.total_bounties, 10);
     assert_eq!(stats.total_locked_amount, 1000);
 }
+
+#[test]
+fn test_get_bounties_sort_by_amount_descending() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_client, token_admin) = create_test_env(&env);
+    let depositor = Address::generate(&env);
+    token_admin.mint(&depositor, &10000);
+
+    let now = env.ledger().timestamp();
+    client.lock_funds(&depositor, &1, &200, &(now + 3000));
+    client.lock_funds(&depositor, &2, &500, &(now + 1000));
+    client.lock_funds(&depositor, &3, &100, &(now + 2000));
+    client.lock_funds(&depositor, &4, &500, &(now + 4000));
+
+    let filter_none = EscrowFilter {
+        statuses: vec![&env],
+        depositor: None,
+        min_amount: None,
+        max_amount: None,
+        start_time: None,
+        end_time: None,
+    };
+
+    let by_amount = client.get_bounties(
+        &filter_none,
+        &Pagination {
+            start_index: 0,
+            limit: 10,
+            sort_by: SortBy::Amount,
+            descending: true,
+        },
+    );
+    assert_eq!(by_amount.len(), 4);
+    // Highest value first; equal amounts keep registry order
+    assert_eq!(by_amount.get(0).unwrap().0, 2);
+    assert_eq!(by_amount.get(1).unwrap().0, 4);
+    assert_eq!(by_amount.get(2).unwrap().0, 1);
+    assert_eq!(by_amount.get(3).unwrap().0, 3);
+
+    // Pagination applies after sorting
+    let page2 = client.get_bounties(
+        &filter_none,
+        &Pagination {
+            start_index: 1,
+            limit: 2,
+            sort_by: SortBy::Amount,
+            descending: true,
+        },
+    );
+    assert_eq!(page2.len(), 2);
+    assert_eq!(page2.get(0).unwrap().0, 4);
+    assert_eq!(page2.get(1).unwrap().0, 1);
+
+    let by_deadline = client.get_bounties(
+        &filter_none,
+        &Pagination {
+            start_index: 0,
+            limit: 2,
+            sort_by: SortBy::Deadline,
+            descending: false,
+        },
+    );
+    assert_eq!(by_deadline.get(0).unwrap().0, 2);
+    assert_eq!(by_deadline.get(1).unwrap().0, 3);
+
+    let by_id_desc = client.get_bounties(
+        &filter_none,
+        &Pagination {
+            start_index: 0,
+            limit: 1,
+            sort_by: SortBy::BountyId,
+            descending: true,
+        },
+    );
+    assert_eq!(by_id_desc.get(0).unwrap().0, 4);
+}
+
+#[test]
+fn test_get_bounties_multiple_statuses() {
+    let env = Env::default();
+    let (client, admin, _token, _token_client, token_admin) = create_test_env(&env);
+    let depositor = Address::generate(&env);
+    token_admin.mint(&depositor, &10000);
+
+    let now = env.ledger().timestamp();
+    client.lock_funds(&depositor, &1, &100, &(now + 100));
+    client.lock_funds(&depositor, &2, &200, &(now + 100));
+    client.lock_funds(&depositor, &3, &300, &(now + 100));
+
+    client.release_funds(&admin, &3, &Address::generate(&env), &None::<i128>);
+    env.ledger().set_timestamp(now + 200);
+    client.refund(&1, &Some(50), &None, &RefundMode::Partial);
+
+    let page = Pagination {
+        start_index: 0,
+        limit: 10,
+        sort_by: SortBy::BountyId,
+        descending: false,
+    };
+
+    // Still-open bounties: Locked or PartiallyRefunded
+    let filter_open = EscrowFilter {
+        statuses: vec![
+            &env,
+            EscrowStatus::Locked as u32,
+            EscrowStatus::PartiallyRefunded as u32,
+        ],
+        depositor: None,
+        min_amount: None,
+        max_amount: None,
+        start_time: None,
+        end_time: None,
+    };
+    let open = client.get_bounties(&filter_open, &page);
+    assert_eq!(open.len(), 2);
+    assert_eq!(
+        open.get(0).unwrap().1.status,
+        EscrowStatus::PartiallyRefunded
+    );
+    assert_eq!(open.get(1).unwrap().1.status, EscrowStatus::Locked);
+
+    // A single status behaves like the old single-status filter
+    let filter_released = EscrowFilter {
+        statuses: vec![&env, EscrowStatus::Released as u32],
+        ..filter_open.clone()
+    };
+    let released = client.get_bounties(&filter_released, &page);
+    assert_eq!(released.len(), 1);
+    assert_eq!(released.get(0).unwrap().0, 3);
+
+    // Empty matches every status
+    let filter_all = EscrowFilter {
+        statuses: vec![&env],
+        ..filter_open
+    };
+    assert_eq!(client.get_bounties(&filter_all, &page).len(), 3);
+}
+
+#[test]
+fn test_count_bounties_matches_get_bounties() {
+    let env = Env::default();
+    let (client, admin, _token, _token_client, token_admin) = create_test_env(&env);
+    let depositor1 = Address::generate(&env);
+    let depositor2 = Address::generate(&env);
+    token_admin.mint(&depositor1, &10000);
+    token_admin.mint(&depositor2, &10000);
+
+    let now = env.ledger().timestamp();
+    client.lock_funds(&depositor1, &1, &100, &(now + 1000));
+    client.lock_funds(&depositor1, &2, &200, &(now + 2000));
+    client.lock_funds(&depositor2, &3, &300, &(now + 2000));
+    client.lock_funds(&depositor2, &4, &400, &(now + 3000));
+    client.release_funds(&admin, &2, &Address::generate(&env), &None::<i128>);
+
+    let page = Pagination {
+        start_index: 0,
+        limit: 100,
+        sort_by: SortBy::BountyId,
+        descending: false,
+    };
+    let filter_none = EscrowFilter {
+        statuses: vec![&env],
+        depositor: None,
+        min_amount: None,
+        max_amount: None,
+        start_time: None,
+        end_time: None,
+    };
+    let filter_locked = EscrowFilter {
+        statuses: vec![&env, EscrowStatus::Locked as u32],
+        ..filter_none.clone()
+    };
+    let filter_dep1_locked = EscrowFilter {
+        depositor: Some(depositor1.clone()),
+        ..filter_locked.clone()
+    };
+    let filter_range = EscrowFilter {
+        min_amount: Some(150),
+        end_time: Some(now + 2000),
+        ..filter_none.clone()
+    };
+
+    for filter in [filter_none, filter_locked, filter_dep1_locked, filter_range] {
+        assert_eq!(
+            client.count_bounties(&filter),
+            client.get_bounties(&filter, &page).len()
+        );
+    }
+    assert_eq!(
+        client.count_bounties(&EscrowFilter {
+            statuses: vec![&env, EscrowStatus::Locked as u32],
+            depositor: Some(depositor2),
+            min_amount: None,
+            max_amount: None,
+            start_time: None,
+            end_time: None,
+        }),
+        2
+    );
+}
+
+#[test]
+fn test_get_bounties_expiring_before() {
+    let env = Env::default();
+    let (client, admin, _token, _token_client, token_admin) = create_test_env(&env);
+    let depositor = Address::generate(&env);
+    token_admin.mint(&depositor, &10000);
+
+    let now = env.ledger().timestamp();
+    let hour = 3600;
+    client.lock_funds(&depositor, &1, &100, &(now + 72 * hour));
+    client.lock_funds(&depositor, &2, &100, &(now + 24 * hour));
+    client.lock_funds(&depositor, &3, &100, &(now + 47 * hour));
+    client.lock_funds(&depositor, &4, &100, &(now + 48 * hour));
+    client.lock_funds(&depositor, &5, &100, &(now + hour));
+
+    // Fully released bounties no longer need a reminder; partial ones do
+    let contributor = Address::generate(&env);
+    client.release_funds(&admin, &2, &contributor, &None::<i128>);
+    client.release_funds(&admin, &3, &contributor, &Some(40));
+
+    let page = |limit: u32| Pagination {
+        start_index: 0,
+        limit,
+        sort_by: SortBy::Deadline,
+        descending: false,
+    };
+    let cutoff = now + 48 * hour;
+
+    let expiring = client.get_bounties_expiring_before(&cutoff, &page(10));
+    let ids: std::vec::Vec<u64> = expiring.iter().map(|(id, _)| id).collect();
+    assert_eq!(ids, [5, 3, 4]);
+    assert_eq!(
+        expiring.get(1).unwrap().1.status,
+        EscrowStatus::PartiallyReleased
+    );
+
+    assert_eq!(
+        client.get_bounties_expiring_before(&cutoff, &page(1)).len(),
+        1
+    );
+    assert_eq!(
+        client.get_bounties_expiring_before(&now, &page(10)).len(),
+        0
+    );
+}
+
+#[test]
+fn test_get_bounties_after_stable_iteration() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_client, token_admin) = create_test_env(&env);
+    let depositor = Address::generate(&env);
+    token_admin.mint(&depositor, &10000);
+
+    let now = env.ledger().timestamp();
+    // Locked out of ID order on purpose
+    for i in [4u64, 1, 5, 2, 3] {
+        client.lock_funds(&depositor, &i, &100, &(now + 1000));
+    }
+
+    let filter_none = EscrowFilter {
+        statuses: vec![&env],
+        depositor: None,
+        min_amount: None,
+        max_amount: None,
+        start_time: None,
+        end_time: None,
+    };
+
+    let mut seen = std::vec::Vec::new();
+    let mut cursor = 0u64;
+    loop {
+        let page = client.get_bounties_after(&filter_none, &cursor, &2);
+        if page.is_empty() {
+            break;
+        }
+        for (bounty_id, _) in page.iter() {
+            seen.push(bounty_id);
+        }
+        cursor = page.last().unwrap().0;
+
+        // A bounty locked mid-scan is picked up exactly once
+        if cursor == 2 {
+            client.lock_funds(&depositor, &6, &100, &(now + 1000));
+        }
+    }
+
+    assert_eq!(seen, std::vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn test_get_bounties_sparse_ids() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_client, token_admin) = create_test_env(&env);
+    let depositor = Address::generate(&env);
+    token_admin.mint(&depositor, &10000);
+
+    let now = env.ledger().timestamp();
+    client.lock_funds(&depositor, &1000, &100, &(now + 1000));
+    client.batch_lock_funds(&vec![
+        &env,
+        LockFundsItem {
+            bounty_id: 999999,
+            depositor: depositor.clone(),
+            amount: 200,
+            deadline: now + 1000,
+        },
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: depositor.clone(),
+            amount: 300,
+            deadline: now + 1000,
+        },
+    ]);
+
+    let filter_none = EscrowFilter {
+        statuses: vec![&env],
+        depositor: None,
+        min_amount: None,
+        max_amount: None,
+        start_time: None,
+        end_time: None,
+    };
+    let bounties = client.get_bounties(
+        &filter_none,
+        &Pagination {
+            start_index: 0,
+            limit: 10,
+            sort_by: SortBy::BountyId,
+            descending: false,
+        },
+    );
+    assert_eq!(bounties.len(), 3);
+    assert_eq!(bounties.get(0).unwrap().0, 1000);
+    assert_eq!(bounties.get(1).unwrap().0, 999999);
+    assert_eq!(bounties.get(2).unwrap().0, 1);
+
+    let stats = client.get_stats();
+    assert_eq!(stats.total_bounties, 3);
+    assert_eq!(stats.total_locked_amount, 600);
+    assert_eq!(client.count_bounties(&filter_none), 3);
+}
+
+#[test]
+fn test_get_escrows_preserves_order_and_gaps() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_client, token_admin) = create_test_env(&env);
+    let depositor = Address::generate(&env);
+    token_admin.mint(&depositor, &10000);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &100, &deadline);
+    client.lock_funds(&depositor, &3, &300, &deadline);
+
+    let escrows = client.get_escrows(&vec![&env, 3, 2, 1, 4]);
+    assert_eq!(escrows.len(), 4);
+    assert_eq!(escrows.get(0).unwrap().unwrap().amount, 300);
+    assert_eq!(escrows.get(1).unwrap(), None);
+    assert_eq!(escrows.get(2).unwrap().unwrap().amount, 100);
+    assert_eq!(escrows.get(3).unwrap(), None);
+
+    let mut too_many = vec![&env];
+    for bounty_id in 0..101u64 {
+        too_many.push_back(bounty_id);
+    }
+    assert_eq!(
+        client.try_get_escrows(&too_many),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+#[test]
+fn test_release_history_paged() {
+    let env = Env::default();
+    let (client, admin, _token, _token_client, token_admin) = create_test_env(&env);
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    token_admin.mint(&depositor, &10000);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &500, &deadline);
+    for amount in 1..=5i128 {
+        client.create_release_schedule(&admin, &1, &(amount * 10), &100, &recipient);
+    }
+    env.ledger().set_timestamp(100);
+    for schedule_id in 1..=5u64 {
+        client.release_schedule_automatic(&1, &schedule_id);
+    }
+    assert_eq!(client.get_release_history_count(&1), 5);
+
+    let page = |start_index: u64, descending: bool| {
+        client.get_release_history_paged(
+            &1,
+            &Pagination {
+                start_index,
+                limit: 2,
+                sort_by: SortBy::BountyId,
+                descending,
+            },
+        )
+    };
+
+    let first = page(0, false);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(0).unwrap().schedule_id, 1);
+    assert_eq!(first.get(1).unwrap().schedule_id, 2);
+
+    let last = page(4, false);
+    assert_eq!(last.len(), 1);
+    assert_eq!(last.get(0).unwrap().amount, 50);
+
+    let newest = page(0, true);
+    assert_eq!(newest.get(0).unwrap().schedule_id, 5);
+    assert_eq!(newest.get(1).unwrap().schedule_id, 4);
+
+    assert_eq!(page(10, false).len(), 0);
+    assert_eq!(client.get_release_history_count(&2), 0);
+}
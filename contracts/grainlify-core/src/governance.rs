@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, BytesN, Symbol, symbol_short};
+use soroban_sdk::{contracttype, token, Address, BytesN, Symbol, Val, Vec, symbol_short};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -9,6 +9,8 @@ pub enum ProposalStatus {
     Rejected,
     Executed,
     Expired,
+    Cancelled,
+    Vetoed,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -26,12 +28,30 @@ pub enum VotingScheme {
     TokenWeighted,
 }
 
+/// What an approved proposal does when executed.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub enum ProposalAction {
+    /// Swap this contract's code for an uploaded WASM
+    UpgradeWasm(BytesN<32>),
+    /// Invoke `function` on `contract`, with this contract as the caller
+    CallContract(ContractCall),
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct ContractCall {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
 #[derive(Clone, Debug)]
 #[contracttype]
 pub struct Proposal {
     pub id: u32,
     pub proposer: Address,
-    pub new_wasm_hash: BytesN<32>,
+    pub action: ProposalAction,
     pub description: Symbol,
     pub created_at: u64,
     pub voting_start: u64,
@@ -42,6 +62,9 @@ pub struct Proposal {
     pub votes_against: i128,
     pub votes_abstain: i128,
     pub total_votes: u32,
+    pub quorum_override: Option<u32>,   // Basis points, replaces config.quorum_percentage
+    pub threshold_override: Option<u32>, // Basis points, replaces config.approval_threshold
+    pub snapshot_ledger: u32, // Token-weighted votes use balances checkpointed before this ledger
 }
 
 #[derive(Clone, Debug)]
@@ -49,10 +72,15 @@ pub struct Proposal {
 pub struct GovernanceConfig {
     pub voting_period: u64,
     pub execution_delay: u64,
+    pub execution_window: u64, // How long after execution_delay an approved proposal stays executable
     pub quorum_percentage: u32,  // Basis points (e.g., 5000 = 50%)
     pub approval_threshold: u32,  // Basis points (e.g., 6667 = 66.67%)
     pub min_proposal_stake: i128,
     pub voting_scheme: VotingScheme,
+    pub governance_token: Address, // Token whose balance is a voter's voting power
+    pub execute_upgrades: bool, // When false, UpgradeWasm proposals can't be executed
+    pub total_voting_supply: i128, // Quorum denominator for TokenWeighted voting
+    pub guardian: Option<Address>, // May veto approved proposals during their execution delay
 }
 
 #[derive(Clone, Debug)]
@@ -65,12 +93,49 @@ pub struct Vote {
     pub timestamp: u64,
 }
 
+/// Page window for `list_proposals`, applied after the status filter.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Pagination {
+    pub start_index: u32,
+    pub limit: u32,
+}
+
+/// A voter's governance token balance recorded at a ledger sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VotingPowerCheckpoint {
+    pub ledger: u32,
+    pub balance: i128,
+}
+
+/// Governance tokens a voter has escrowed by casting token-weighted votes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VoteLock {
+    pub amount: i128,
+    pub unlock_at: u64, // Latest voting_end among the proposals voted on
+}
+
 // Storage keys
 pub const PROPOSALS: Symbol = symbol_short!("PROPOSALS");
 pub const PROPOSAL_COUNT: Symbol = symbol_short!("PROP_CNT");
 pub const VOTES: Symbol = symbol_short!("VOTES");
 pub const GOVERNANCE_CONFIG: Symbol = symbol_short!("GOV_CFG");
+pub const CHECKPOINTS: Symbol = symbol_short!("CHKPTS");
+pub const VOTE_LOCKS: Symbol = symbol_short!("VOTE_LCK");
 pub const VOTER_REGISTRY: Symbol = symbol_short!("VOTERS");
+pub const VOTER_COUNT: Symbol = symbol_short!("VOTER_CNT");
+pub const GOVERNANCE_ADMIN: Symbol = symbol_short!("GOV_ADMIN");
+pub const OPEN_ENROLLMENT: Symbol = symbol_short!("OPEN_ENRL");
+
+/// Shortest voting period governance can be configured with (1 hour).
+/// Prevents proposals that can be finalized right after they are created.
+pub const MIN_VOTING_PERIOD: u64 = 60 * 60;
+
+/// Longest execution delay governance can be configured with (30 days).
+/// Prevents approved proposals from being locked out of execution indefinitely.
+pub const MAX_EXECUTION_DELAY: u64 = 30 * 24 * 60 * 60;
 
 #[soroban_sdk::contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -90,6 +155,16 @@ pub enum Error {
     ProposalNotApproved = 12,
     ExecutionDelayNotMet = 13,
     ProposalExpired = 14,
+    VotingPeriodTooShort = 15,
+    AlreadyRegistered = 16,
+    NotVoted = 17,
+    VoterNotRegistered = 18,
+    ProposalNotExpired = 19,
+    ExecutionDelayTooLong = 20,
+    GuardianNotSet = 21,
+    VetoWindowClosed = 22,
+    UpgradesDisabled = 23,
+    TokensLocked = 24,
 }
 
 pub struct GovernanceContract;
@@ -112,9 +187,22 @@ impl GovernanceContract {
         if config.approval_threshold < 5000 {
             return Err(Error::ThresholdTooLow); // Must be > 50%
         }
+
+        if config.voting_period < MIN_VOTING_PERIOD {
+            return Err(Error::VotingPeriodTooShort);
+        }
+
+        if config.execution_delay > MAX_EXECUTION_DELAY {
+            return Err(Error::ExecutionDelayTooLong);
+        }
+
+        if config.voting_scheme == VotingScheme::TokenWeighted && config.total_voting_supply <= 0 {
+            return Err(Error::InvalidThreshold);
+        }
         
         // Store config
         env.storage().instance().set(&GOVERNANCE_CONFIG, &config);
+        env.storage().instance().set(&GOVERNANCE_ADMIN, &admin);
         env.storage().instance().set(&PROPOSAL_COUNT, &0u32);
         
         // Emit event
@@ -126,12 +214,14 @@ impl GovernanceContract {
         Ok(())
     }
 
-    /// Create a new upgrade proposal
+    /// Create a new proposal
     pub fn create_proposal(
         env: &soroban_sdk::Env,
         proposer: Address,
-        new_wasm_hash: BytesN<32>,
+        action: ProposalAction,
         description: Symbol,
+        quorum_override: Option<u32>,
+        threshold_override: Option<u32>,
     ) -> Result<u32, Error> {
         // Authenticate proposer
         proposer.require_auth();
@@ -143,6 +233,18 @@ impl GovernanceContract {
             .get(&GOVERNANCE_CONFIG)
             .ok_or(Error::NotInitialized)?;
         
+        // Overrides may only tighten the global requirements
+        if let Some(quorum) = quorum_override {
+            if quorum < config.quorum_percentage || quorum > 10000 {
+                return Err(Error::InvalidThreshold);
+            }
+        }
+        if let Some(threshold) = threshold_override {
+            if threshold < config.approval_threshold || threshold > 10000 {
+                return Err(Error::InvalidThreshold);
+            }
+        }
+        
         // Check minimum stake requirement
         let proposer_balance = Self::get_voting_power(env, &proposer)?;
         if proposer_balance < config.min_proposal_stake {
@@ -162,7 +264,7 @@ impl GovernanceContract {
         let proposal = Proposal {
             id: proposal_id,
             proposer: proposer.clone(),
-            new_wasm_hash,
+            action,
             description: description.clone(),
             created_at: current_time,
             voting_start: current_time,
@@ -173,6 +275,9 @@ impl GovernanceContract {
             votes_against: 0,
             votes_abstain: 0,
             total_votes: 0,
+            quorum_override,
+            threshold_override,
+            snapshot_ledger: env.ledger().sequence(),
         };
         
         // Store proposal
@@ -199,11 +304,248 @@ impl GovernanceContract {
         Ok(proposal_id)
     }
     
-    /// Get voting power for an address
-    pub fn get_voting_power(_env: &soroban_sdk::Env, _voter: &Address) -> Result<i128, Error> {
-        // TODO: Integrate with token contract or use native balance
-        // For now, assume equal voting power of 1 for testing purposes
-        Ok(100) // Returns 100 to pass any min_stake check for now
+    /// Get voting power for an address (its governance token balance)
+    pub fn get_voting_power(env: &soroban_sdk::Env, voter: &Address) -> Result<i128, Error> {
+        let config: GovernanceConfig = env
+            .storage()
+            .instance()
+            .get(&GOVERNANCE_CONFIG)
+            .ok_or(Error::NotInitialized)?;
+
+        let locked = Self::get_vote_lock(env, voter).map_or(0, |lock| lock.amount);
+        Ok(token::Client::new(env, &config.governance_token).balance(voter) + locked)
+    }
+
+    /// Add a voter to the registry used as the OnePersonOneVote quorum denominator.
+    ///
+    /// With open enrollment (the default) voters register themselves; once
+    /// it's closed only the governance admin can add them.
+    pub fn register_voter(env: &soroban_sdk::Env, voter: Address) -> Result<u32, Error> {
+        if Self::is_open_enrollment(env) {
+            voter.require_auth();
+        } else {
+            Self::get_admin(env)?.require_auth();
+        }
+
+        if !env.storage().instance().has(&GOVERNANCE_CONFIG) {
+            return Err(Error::NotInitialized);
+        }
+
+        let mut registry: soroban_sdk::Map<Address, bool> = env
+            .storage()
+            .instance()
+            .get(&VOTER_REGISTRY)
+            .unwrap_or(soroban_sdk::Map::new(env));
+        if registry.contains_key(voter.clone()) {
+            return Err(Error::AlreadyRegistered);
+        }
+        registry.set(voter.clone(), true);
+
+        let count = Self::get_voter_count(env) + 1;
+        env.storage().instance().set(&VOTER_REGISTRY, &registry);
+        env.storage().instance().set(&VOTER_COUNT, &count);
+
+        env.events().publish((symbol_short!("voter_reg"), voter), count);
+
+        Ok(count)
+    }
+
+    /// Number of registered voters
+    pub fn get_voter_count(env: &soroban_sdk::Env) -> u32 {
+        env.storage().instance().get(&VOTER_COUNT).unwrap_or(0)
+    }
+
+    /// Whether `voter` is in the registry
+    pub fn is_registered_voter(env: &soroban_sdk::Env, voter: &Address) -> bool {
+        env.storage()
+            .instance()
+            .get::<_, soroban_sdk::Map<Address, bool>>(&VOTER_REGISTRY)
+            .map(|registry| registry.contains_key(voter.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Open or close self-registration of voters (governance admin only)
+    pub fn set_open_enrollment(env: &soroban_sdk::Env, open: bool) -> Result<(), Error> {
+        Self::get_admin(env)?.require_auth();
+        env.storage().instance().set(&OPEN_ENROLLMENT, &open);
+        Ok(())
+    }
+
+    /// Whether voters can register themselves
+    pub fn is_open_enrollment(env: &soroban_sdk::Env) -> bool {
+        env.storage().instance().get(&OPEN_ENROLLMENT).unwrap_or(true)
+    }
+
+    fn get_admin(env: &soroban_sdk::Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&GOVERNANCE_ADMIN)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Record the voter's current token balance as a voting power checkpoint.
+    ///
+    /// The token contract has no balance history, so token-weighted votes use
+    /// the last checkpoint taken before a proposal's snapshot ledger, capped
+    /// at the tokens held when voting. Tokens acquired after a proposal
+    /// opens therefore can't inflate a vote on it. Tokens escrowed by earlier
+    /// votes still count towards the balance.
+    pub fn checkpoint_voting_power(
+        env: &soroban_sdk::Env,
+        voter: Address,
+    ) -> Result<VotingPowerCheckpoint, Error> {
+        voter.require_auth();
+
+        let balance = Self::get_voting_power(env, &voter)?;
+        let checkpoint = VotingPowerCheckpoint {
+            ledger: env.ledger().sequence(),
+            balance,
+        };
+
+        let key = (CHECKPOINTS, voter);
+        let mut history: Vec<VotingPowerCheckpoint> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+
+        // Several checkpoints in one ledger collapse into the latest
+        if let Some(last) = history.last() {
+            if last.ledger == checkpoint.ledger {
+                history.pop_back();
+            }
+        }
+        history.push_back(checkpoint.clone());
+        env.storage().persistent().set(&key, &history);
+
+        Ok(checkpoint)
+    }
+
+    /// Voting power from the latest checkpoint strictly before `ledger` (0 if none)
+    pub fn get_voting_power_at(env: &soroban_sdk::Env, voter: &Address, ledger: u32) -> i128 {
+        let history: Vec<VotingPowerCheckpoint> = env
+            .storage()
+            .persistent()
+            .get(&(CHECKPOINTS, voter.clone()))
+            .unwrap_or(Vec::new(env));
+
+        let mut power = 0;
+        for checkpoint in history.iter() {
+            if checkpoint.ledger >= ledger {
+                break;
+            }
+            power = checkpoint.balance;
+        }
+        power
+    }
+
+    /// Tokens `voter` has escrowed by voting, if any
+    pub fn get_vote_lock(env: &soroban_sdk::Env, voter: &Address) -> Option<VoteLock> {
+        env.storage().persistent().get(&(VOTE_LOCKS, voter.clone()))
+    }
+
+    /// Return the tokens escrowed by the voter's token-weighted votes once
+    /// voting has ended on every proposal they were used for.
+    ///
+    /// Returns the amount sent back (0 if nothing was escrowed).
+    pub fn withdraw_vote_lock(env: &soroban_sdk::Env, voter: Address) -> Result<i128, Error> {
+        voter.require_auth();
+
+        let lock = match Self::get_vote_lock(env, &voter) {
+            Some(lock) => lock,
+            None => return Ok(0),
+        };
+        if env.ledger().timestamp() <= lock.unlock_at {
+            return Err(Error::TokensLocked);
+        }
+
+        let config: GovernanceConfig = env
+            .storage()
+            .instance()
+            .get(&GOVERNANCE_CONFIG)
+            .ok_or(Error::NotInitialized)?;
+        env.storage().persistent().remove(&(VOTE_LOCKS, voter.clone()));
+        token::Client::new(env, &config.governance_token).transfer(
+            &env.current_contract_address(),
+            &voter,
+            &lock.amount,
+        );
+
+        env.events()
+            .publish((symbol_short!("vote_unlk"), voter), lock.amount);
+
+        Ok(lock.amount)
+    }
+
+    /// Escrow enough of the voter's tokens to cover `voting_power` until
+    /// `voting_end`. Tokens already escrowed are reused, so one holding can
+    /// vote on several open proposals but can't be moved to another voter
+    /// until all of them have closed.
+    fn lock_voting_tokens(
+        env: &soroban_sdk::Env,
+        config: &GovernanceConfig,
+        voter: &Address,
+        voting_power: i128,
+        voting_end: u64,
+    ) {
+        let mut lock = Self::get_vote_lock(env, voter).unwrap_or(VoteLock {
+            amount: 0,
+            unlock_at: 0,
+        });
+        if voting_power > lock.amount {
+            token::Client::new(env, &config.governance_token).transfer(
+                voter,
+                &env.current_contract_address(),
+                &(voting_power - lock.amount),
+            );
+            lock.amount = voting_power;
+        }
+        lock.unlock_at = lock.unlock_at.max(voting_end);
+        env.storage()
+            .persistent()
+            .set(&(VOTE_LOCKS, voter.clone()), &lock);
+    }
+
+    /// Fetch a single proposal
+    pub fn get_proposal(env: &soroban_sdk::Env, proposal_id: u32) -> Result<Proposal, Error> {
+        let proposals: soroban_sdk::Map<u32, Proposal> = env
+            .storage()
+            .instance()
+            .get(&PROPOSALS)
+            .unwrap_or(soroban_sdk::Map::new(env));
+        proposals.get(proposal_id).ok_or(Error::ProposalNotFound)
+    }
+
+    /// Proposals in id order, optionally only those with `status`
+    pub fn list_proposals(
+        env: &soroban_sdk::Env,
+        status: Option<ProposalStatus>,
+        pagination: Pagination,
+    ) -> Vec<Proposal> {
+        let proposals: soroban_sdk::Map<u32, Proposal> = env
+            .storage()
+            .instance()
+            .get(&PROPOSALS)
+            .unwrap_or(soroban_sdk::Map::new(env));
+
+        let mut result = Vec::new(env);
+        let mut skipped = 0u32;
+        for proposal in proposals.values().iter() {
+            if result.len() >= pagination.limit {
+                break;
+            }
+            if let Some(wanted) = &status {
+                if &proposal.status != wanted {
+                    continue;
+                }
+            }
+            if skipped < pagination.start_index {
+                skipped += 1;
+                continue;
+            }
+            result.push_back(proposal);
+        }
+        result
     }
 
     /// Cast a vote on a proposal
@@ -241,9 +583,10 @@ impl GovernanceContract {
             return Err(Error::VotingEnded);
         }
         
-        // Check for double voting
+        // Check for double voting. The same map is updated below, so the
+        // guard and the recorded vote can't diverge.
         let vote_key = (proposal_id, voter.clone());
-        let votes_map: soroban_sdk::Map<(u32, Address), Vote> = env
+        let mut votes_map: soroban_sdk::Map<(u32, Address), Vote> = env
             .storage()
             .instance()
             .get(&VOTES)
@@ -261,29 +604,26 @@ impl GovernanceContract {
             .ok_or(Error::NotInitialized)?;
         
         let voting_power = match config.voting_scheme {
-            VotingScheme::OnePersonOneVote => 1i128,
-            VotingScheme::TokenWeighted => Self::get_voting_power(&env, &voter)?,
+            VotingScheme::OnePersonOneVote => {
+                // Only registered voters count towards the quorum denominator
+                if !Self::is_registered_voter(&env, &voter) {
+                    return Err(Error::VoterNotRegistered);
+                }
+                1i128
+            }
+            VotingScheme::TokenWeighted => {
+                // Capped by the tokens still held, and those tokens stay
+                // escrowed until voting ends, so they can't be handed to
+                // another voter and counted again on this proposal
+                let power = Self::get_voting_power_at(&env, &voter, proposal.snapshot_ledger)
+                    .min(Self::get_voting_power(&env, &voter)?);
+                Self::lock_voting_tokens(&env, &config, &voter, power, proposal.voting_end);
+                power
+            }
         };
         
-        // Record vote (for audit, even though we have the bug)
-        let vote = Vote {
-            voter: voter.clone(),
-            proposal_id,
-            vote_type: vote_type.clone(),
-            voting_power,
-            timestamp: current_time,
-        };
-        
-        let mut votes_map_mut: soroban_sdk::Map<(u32, Address), Vote> = env
-            .storage()
-            .instance()
-            .get(&VOTES)
-            .unwrap_or(soroban_sdk::Map::new(&env));
-        
-        votes_map_mut.set((proposal_id, voter.clone()), vote);
-        env.storage().instance().set(&VOTES, &votes_map_mut);
-        
-        // Update proposal tallies
+        // Tally and record the vote together; both maps are written back in
+        // the same invocation, so a vote is counted exactly once.
         match vote_type {
             VoteType::For => proposal.votes_for += voting_power,
             VoteType::Against => proposal.votes_against += voting_power,
@@ -291,7 +631,19 @@ impl GovernanceContract {
         }
         proposal.total_votes += 1;
         
-        proposals.set(proposal_id, proposal.clone());
+        votes_map.set(
+            vote_key,
+            Vote {
+                voter: voter.clone(),
+                proposal_id,
+                vote_type: vote_type.clone(),
+                voting_power,
+                timestamp: current_time,
+            },
+        );
+        proposals.set(proposal_id, proposal);
+        
+        env.storage().instance().set(&VOTES, &votes_map);
         env.storage().instance().set(&PROPOSALS, &proposals);
         
         // Emit event
@@ -303,7 +655,115 @@ impl GovernanceContract {
         Ok(())
     }
 
+    /// Switch an existing vote to `new_vote_type` while voting is open.
+    ///
+    /// The voter's recorded voting power moves from the old tally to the new
+    /// one, so `total_votes` is unchanged.
+    pub fn change_vote(
+        env: soroban_sdk::Env,
+        voter: Address,
+        proposal_id: u32,
+        new_vote_type: VoteType,
+    ) -> Result<(), Error> {
+        voter.require_auth();
+        
+        let mut proposals: soroban_sdk::Map<u32, Proposal> = env
+            .storage()
+            .instance()
+            .get(&PROPOSALS)
+            .ok_or(Error::ProposalsNotFound)?;
+        
+        let mut proposal = proposals
+            .get(proposal_id)
+            .ok_or(Error::ProposalNotFound)?;
+        
+        if proposal.status != ProposalStatus::Active {
+            return Err(Error::ProposalNotActive);
+        }
+        
+        let current_time = env.ledger().timestamp();
+        if current_time > proposal.voting_end {
+            return Err(Error::VotingEnded);
+        }
+        
+        let vote_key = (proposal_id, voter.clone());
+        let mut votes_map: soroban_sdk::Map<(u32, Address), Vote> = env
+            .storage()
+            .instance()
+            .get(&VOTES)
+            .unwrap_or(soroban_sdk::Map::new(&env));
+        
+        let mut vote = votes_map.get(vote_key.clone()).ok_or(Error::NotVoted)?;
+        let old_vote_type = vote.vote_type.clone();
+        
+        match old_vote_type {
+            VoteType::For => proposal.votes_for -= vote.voting_power,
+            VoteType::Against => proposal.votes_against -= vote.voting_power,
+            VoteType::Abstain => proposal.votes_abstain -= vote.voting_power,
+        }
+        match new_vote_type {
+            VoteType::For => proposal.votes_for += vote.voting_power,
+            VoteType::Against => proposal.votes_against += vote.voting_power,
+            VoteType::Abstain => proposal.votes_abstain += vote.voting_power,
+        }
+        
+        vote.vote_type = new_vote_type.clone();
+        vote.timestamp = current_time;
+        votes_map.set(vote_key, vote);
+        proposals.set(proposal_id, proposal);
+        
+        env.storage().instance().set(&VOTES, &votes_map);
+        env.storage().instance().set(&PROPOSALS, &proposals);
+        
+        env.events().publish(
+            (symbol_short!("vote_chg"), voter),
+            (proposal_id, old_vote_type, new_vote_type),
+        );
+        
+        Ok(())
+    }
+
+    /// Withdraw a proposal before voting ends (proposer only).
+    ///
+    /// `Cancelled` is terminal: the proposal can't be voted on, finalized or
+    /// executed, and any votes already cast are discarded with it.
+    pub fn cancel_proposal(env: soroban_sdk::Env, proposal_id: u32) -> Result<(), Error> {
+        let mut proposals: soroban_sdk::Map<u32, Proposal> = env
+            .storage()
+            .instance()
+            .get(&PROPOSALS)
+            .ok_or(Error::ProposalsNotFound)?;
+        
+        let mut proposal = proposals
+            .get(proposal_id)
+            .ok_or(Error::ProposalNotFound)?;
+        
+        proposal.proposer.require_auth();
+        
+        if proposal.status != ProposalStatus::Active {
+            return Err(Error::ProposalNotActive);
+        }
+        if env.ledger().timestamp() > proposal.voting_end {
+            return Err(Error::VotingEnded);
+        }
+        
+        proposal.status = ProposalStatus::Cancelled;
+        proposals.set(proposal_id, proposal.clone());
+        env.storage().instance().set(&PROPOSALS, &proposals);
+        
+        env.events().publish(
+            (symbol_short!("cancel"), proposal.proposer),
+            proposal_id,
+        );
+        
+        Ok(())
+    }
+
     /// Finalize a proposal (check votes and update status)
+    ///
+    /// Quorum counts every cast vote, abstentions included. Approval is
+    /// `votes_for / (votes_for + votes_against)`, so abstaining helps a
+    /// proposal reach quorum without counting for or against it.
     pub fn finalize_proposal(
         env: soroban_sdk::Env,
         proposal_id: u32,
@@ -338,46 +798,49 @@ impl GovernanceContract {
             .get(&GOVERNANCE_CONFIG)
             .ok_or(Error::NotInitialized)?;
         
-        // Calculate total possible votes (placeholder for now)
-        let total_possible_votes = 1000i128; 
+        // Total possible votes: token supply, or one per registered voter
+        let total_possible_votes = match config.voting_scheme {
+            VotingScheme::TokenWeighted => config.total_voting_supply,
+            VotingScheme::OnePersonOneVote => Self::get_voter_count(&env) as i128,
+        };
         
         let total_cast_votes = proposal.votes_for + proposal.votes_against + proposal.votes_abstain;
         
-        // Check quorum
-        let quorum_met = (total_cast_votes * 10000) / total_possible_votes >= config.quorum_percentage as i128;
+        let quorum_percentage = proposal.quorum_override.unwrap_or(config.quorum_percentage);
+        let approval_threshold = proposal.threshold_override.unwrap_or(config.approval_threshold);
         
-        if !quorum_met {
-            proposal.status = ProposalStatus::Rejected;
-            proposals.set(proposal_id, proposal.clone());
-            env.storage().instance().set(&PROPOSALS, &proposals);
-            return Ok(ProposalStatus::Rejected);
-        }
+        // Check quorum (nobody to count means quorum can't be met)
+        let quorum_met = total_possible_votes > 0
+            && (total_cast_votes * 10000) / total_possible_votes >= quorum_percentage as i128;
         
         // Check approval threshold (excluding abstentions)
         let votes_cast_for_or_against = proposal.votes_for + proposal.votes_against;
         
-        if votes_cast_for_or_against == 0 {
-            proposal.status = ProposalStatus::Rejected;
-            proposals.set(proposal_id, proposal.clone());
-            env.storage().instance().set(&PROPOSALS, &proposals);
-            return Ok(ProposalStatus::Rejected);
-        }
+        let approved = quorum_met
+            && votes_cast_for_or_against > 0
+            && (proposal.votes_for * 10000) / votes_cast_for_or_against
+                >= approval_threshold as i128;
         
-        let approval_percentage = (proposal.votes_for * 10000) / votes_cast_for_or_against;
-        
-        if approval_percentage >= config.approval_threshold as i128 {
-            proposal.status = ProposalStatus::Approved;
+        proposal.status = if approved {
+            ProposalStatus::Approved
         } else {
-            proposal.status = ProposalStatus::Rejected;
-        }
+            ProposalStatus::Rejected
+        };
         
         proposals.set(proposal_id, proposal.clone());
         env.storage().instance().set(&PROPOSALS, &proposals);
         
-        // Emit event
+        // Emit event with the tallies so indexers don't need to read storage
         env.events().publish(
             (symbol_short!("finalize"), proposal_id),
-            proposal.status.clone(),
+            (
+                proposal.status.clone(),
+                proposal.votes_for,
+                proposal.votes_against,
+                proposal.votes_abstain,
+                proposal.total_votes,
+                quorum_met,
+            ),
         );
         
         Ok(proposal.status)
@@ -416,8 +879,15 @@ impl GovernanceContract {
             return Err(Error::ExecutionDelayNotMet);
         }
         
-        // Check not expired
-        let expiration = earliest_execution + (7 * 24 * 60 * 60); // 7 days after execution window
+        let config: GovernanceConfig = env
+            .storage()
+            .instance()
+            .get(&GOVERNANCE_CONFIG)
+            .ok_or(Error::NotInitialized)?;
+        
+        // Check not expired. The error reverts the status write below, so
+        // `expire_proposal` is what actually records the expiry.
+        let expiration = earliest_execution + config.execution_window;
         if current_time > expiration {
             proposal.status = ProposalStatus::Expired;
             proposals.set(proposal_id, proposal);
@@ -425,14 +895,30 @@ impl GovernanceContract {
             return Err(Error::ProposalExpired);
         }
         
-        // Execute the upgrade (disabled in tests if causing issues, or use dummy)
-        // env.deployer().update_current_contract_wasm(proposal.new_wasm_hash.clone());
+        // An upgrade that wouldn't run stays Approved instead of being
+        // reported as executed
+        if matches!(proposal.action, ProposalAction::UpgradeWasm(_)) && !config.execute_upgrades {
+            return Err(Error::UpgradesDisabled);
+        }
         
-        // Mark as executed
+        // Mark as executed before running the action so a re-entrant call
+        // or the new WASM sees final state
+        let action = proposal.action.clone();
         proposal.status = ProposalStatus::Executed;
         proposals.set(proposal_id, proposal);
         env.storage().instance().set(&PROPOSALS, &proposals);
         
+        // Run the action. Either branch traps (reverting everything above) on
+        // failure, so the event below is only emitted on success.
+        match action {
+            ProposalAction::UpgradeWasm(new_wasm_hash) => {
+                env.deployer().update_current_contract_wasm(new_wasm_hash);
+            }
+            ProposalAction::CallContract(call) => {
+                env.invoke_contract::<Val>(&call.contract, &call.function, call.args);
+            }
+        }
+        
         // Emit event
         env.events().publish(
             (symbol_short!("execute"), executor.clone()),
@@ -441,4 +927,86 @@ impl GovernanceContract {
         
         Ok(())
     }
+
+    /// Veto an approved proposal before it becomes executable (guardian only).
+    ///
+    /// This is the emergency brake for a malicious proposal that passed a
+    /// vote. `Vetoed` is terminal, so `execute_proposal` rejects it.
+    pub fn veto_proposal(env: soroban_sdk::Env, proposal_id: u32) -> Result<(), Error> {
+        let config: GovernanceConfig = env
+            .storage()
+            .instance()
+            .get(&GOVERNANCE_CONFIG)
+            .ok_or(Error::NotInitialized)?;
+        let guardian = config.guardian.ok_or(Error::GuardianNotSet)?;
+        guardian.require_auth();
+        
+        let mut proposals: soroban_sdk::Map<u32, Proposal> = env
+            .storage()
+            .instance()
+            .get(&PROPOSALS)
+            .ok_or(Error::ProposalsNotFound)?;
+        
+        let mut proposal = proposals
+            .get(proposal_id)
+            .ok_or(Error::ProposalNotFound)?;
+        
+        if proposal.status != ProposalStatus::Approved {
+            return Err(Error::ProposalNotApproved);
+        }
+        
+        // Only while the execution delay is running
+        let earliest_execution = proposal.voting_end + proposal.execution_delay;
+        if env.ledger().timestamp() >= earliest_execution {
+            return Err(Error::VetoWindowClosed);
+        }
+        
+        proposal.status = ProposalStatus::Vetoed;
+        proposals.set(proposal_id, proposal);
+        env.storage().instance().set(&PROPOSALS, &proposals);
+        
+        env.events().publish((symbol_short!("veto"), guardian), proposal_id);
+        
+        Ok(())
+    }
+
+    /// Mark an approved proposal `Expired` once its execution window has passed.
+    ///
+    /// Anyone can call this. `execute_proposal` rejects late executions with
+    /// `ProposalExpired`, but that error also reverts its own status update.
+    pub fn expire_proposal(env: soroban_sdk::Env, proposal_id: u32) -> Result<(), Error> {
+        let mut proposals: soroban_sdk::Map<u32, Proposal> = env
+            .storage()
+            .instance()
+            .get(&PROPOSALS)
+            .ok_or(Error::ProposalsNotFound)?;
+        
+        let mut proposal = proposals
+            .get(proposal_id)
+            .ok_or(Error::ProposalNotFound)?;
+        
+        if proposal.status != ProposalStatus::Approved {
+            return Err(Error::ProposalNotApproved);
+        }
+        
+        let config: GovernanceConfig = env
+            .storage()
+            .instance()
+            .get(&GOVERNANCE_CONFIG)
+            .ok_or(Error::NotInitialized)?;
+        
+        let expiration =
+            proposal.voting_end + proposal.execution_delay + config.execution_window;
+        if env.ledger().timestamp() <= expiration {
+            return Err(Error::ProposalNotExpired);
+        }
+        
+        proposal.status = ProposalStatus::Expired;
+        proposals.set(proposal_id, proposal);
+        env.storage().instance().set(&PROPOSALS, &proposals);
+        
+        env.events().publish((symbol_short!("expire"),), proposal_id);
+        
+        Ok(())
+    }
 }
@@ -162,7 +162,8 @@ mod governance;
 mod test;
 use multisig::MultiSig;
 pub use governance::{
-    Error as GovError, Proposal, ProposalStatus, VoteType, VotingScheme, GovernanceConfig, Vote
+    ContractCall, Error as GovError, Pagination, Proposal, ProposalAction, ProposalStatus, VoteType, VotingScheme, GovernanceConfig, Vote,
+    VoteLock, VotingPowerCheckpoint, MAX_EXECUTION_DELAY, MIN_VOTING_PERIOD,
 };
 use soroban_sdk::{
     contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec, String,
@@ -264,6 +265,7 @@ mod monitoring {
     pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
         let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
         let time_key = (Symbol::new(env, "perf_time"), function.clone());
+        let last_key = (Symbol::new(env, "perf_last"), function.clone());
 
         let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
         let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
@@ -272,6 +274,9 @@ mod monitoring {
         env.storage()
             .persistent()
             .set(&time_key, &(total + duration));
+        env.storage()
+            .persistent()
+            .set(&last_key, &env.ledger().timestamp());
 
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("perf")),
@@ -344,7 +349,7 @@ mod monitoring {
         let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
         let last: u64 = env.storage().persistent().get(&last_key).unwrap_or(0);
 
-        let avg = if count > 0 { total / count } else { 0 };
+        let avg = total.checked_div(count).unwrap_or(0);
 
         PerformanceStats {
             function_name,
@@ -535,14 +540,84 @@ impl GrainlifyContract {
         governance::GovernanceContract::init_governance(&env, admin, config)
     }
 
-    /// Create a new upgrade proposal
+    /// Create a new proposal to upgrade this contract or call another one
+    ///
+    /// `quorum_override` / `threshold_override` (basis points) replace the
+    /// global config for this proposal only and may not be lower than it.
     pub fn create_proposal(
         env: Env,
         proposer: Address,
-        new_wasm_hash: BytesN<32>,
+        action: governance::ProposalAction,
         description: Symbol,
+        quorum_override: Option<u32>,
+        threshold_override: Option<u32>,
     ) -> Result<u32, governance::Error> {
-        governance::GovernanceContract::create_proposal(&env, proposer, new_wasm_hash, description)
+        governance::GovernanceContract::create_proposal(
+            &env,
+            proposer,
+            action,
+            description,
+            quorum_override,
+            threshold_override,
+        )
+    }
+
+    /// Register a voter for OnePersonOneVote quorum counting
+    pub fn register_voter(env: Env, voter: Address) -> Result<u32, governance::Error> {
+        governance::GovernanceContract::register_voter(&env, voter)
+    }
+
+    /// Number of registered voters
+    pub fn get_voter_count(env: Env) -> u32 {
+        governance::GovernanceContract::get_voter_count(&env)
+    }
+
+    /// Whether `voter` is registered
+    pub fn is_registered_voter(env: Env, voter: Address) -> bool {
+        governance::GovernanceContract::is_registered_voter(&env, &voter)
+    }
+
+    /// Open or close voter self-registration (governance admin only)
+    pub fn set_open_enrollment(env: Env, open: bool) -> Result<(), governance::Error> {
+        governance::GovernanceContract::set_open_enrollment(&env, open)
+    }
+
+    /// Whether voters can register themselves
+    pub fn is_open_enrollment(env: Env) -> bool {
+        governance::GovernanceContract::is_open_enrollment(&env)
+    }
+
+    /// Checkpoint the voter's governance token balance for token-weighted votes
+    pub fn checkpoint_voting_power(
+        env: Env,
+        voter: Address,
+    ) -> Result<governance::VotingPowerCheckpoint, governance::Error> {
+        governance::GovernanceContract::checkpoint_voting_power(&env, voter)
+    }
+
+    /// Get the governance tokens a voter has escrowed by voting, if any
+    pub fn get_vote_lock(env: Env, voter: Address) -> Option<governance::VoteLock> {
+        governance::GovernanceContract::get_vote_lock(&env, &voter)
+    }
+
+    /// Return a voter's escrowed governance tokens once voting has ended on
+    /// every proposal they were used for
+    pub fn withdraw_vote_lock(env: Env, voter: Address) -> Result<i128, governance::Error> {
+        governance::GovernanceContract::withdraw_vote_lock(&env, voter)
+    }
+
+    /// Get a proposal by id
+    pub fn get_proposal(env: Env, proposal_id: u32) -> Result<governance::Proposal, governance::Error> {
+        governance::GovernanceContract::get_proposal(&env, proposal_id)
+    }
+
+    /// List proposals in id order, optionally filtered by status
+    pub fn list_proposals(
+        env: Env,
+        status: Option<governance::ProposalStatus>,
+        pagination: governance::Pagination,
+    ) -> Vec<governance::Proposal> {
+        governance::GovernanceContract::list_proposals(&env, status, pagination)
     }
 
     /// Cast a vote on a proposal
@@ -555,6 +630,21 @@ impl GrainlifyContract {
         governance::GovernanceContract::cast_vote(env, voter, proposal_id, vote_type)
     }
 
+    /// Change an existing vote before voting ends
+    pub fn change_vote(
+        env: Env,
+        voter: Address,
+        proposal_id: u32,
+        new_vote_type: governance::VoteType,
+    ) -> Result<(), governance::Error> {
+        governance::GovernanceContract::change_vote(env, voter, proposal_id, new_vote_type)
+    }
+
+    /// Cancel a proposal before voting ends (proposer only)
+    pub fn cancel_proposal(env: Env, proposal_id: u32) -> Result<(), governance::Error> {
+        governance::GovernanceContract::cancel_proposal(env, proposal_id)
+    }
+
     /// Finalize a proposal
     pub fn finalize_proposal(
         env: Env,
@@ -572,6 +662,16 @@ impl GrainlifyContract {
         governance::GovernanceContract::execute_proposal(env, executor, proposal_id)
     }
 
+    /// Veto an approved proposal during its execution delay (guardian only)
+    pub fn veto_proposal(env: Env, proposal_id: u32) -> Result<(), governance::Error> {
+        governance::GovernanceContract::veto_proposal(env, proposal_id)
+    }
+
+    /// Mark an approved proposal expired once its execution window has passed
+    pub fn expire_proposal(env: Env, proposal_id: u32) -> Result<(), governance::Error> {
+        governance::GovernanceContract::expire_proposal(env, proposal_id)
+    }
+
     /// Initializes the contract with a single admin address.
     ///
     /// # Arguments
@@ -640,6 +740,26 @@ impl GrainlifyContract {
         MultiSig::approve(&env, proposal_id, signer);
     }
 
+    /// Executes an upgrade proposal that has met the multisig threshold.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `proposal_id` - The ID of the upgrade proposal to execute
+    pub fn execute_upgrade(env: Env, proposal_id: u64) {
+        if !MultiSig::can_execute(&env, proposal_id) {
+            panic!("Threshold not met");
+        }
+
+        let wasm_hash: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::UpgradeProposal(proposal_id))
+            .expect("Missing upgrade proposal");
+
+        env.deployer().update_current_contract_wasm(wasm_hash);
+
+        MultiSig::mark_executed(&env, proposal_id);
+    }
 
     /// Upgrades the contract to new WASM code.
     ///
@@ -732,33 +852,6 @@ impl GrainlifyContract {
     /// # Panics
     /// * If admin address is not set (contract not initialized)
     /// * If caller is not the admin
-
-    /// Executes an upgrade proposal that has met the multisig threshold.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `proposal_id` - The ID of the upgrade proposal to execute
-    pub fn execute_upgrade(env: Env, proposal_id: u64) {
-        if !MultiSig::can_execute(&env, proposal_id) {
-            panic!("Threshold not met");
-        }
-
-        let wasm_hash: BytesN<32> = env
-            .storage()
-            .instance()
-            .get(&DataKey::UpgradeProposal(proposal_id))
-            .expect("Missing upgrade proposal");
-
-        env.deployer().update_current_contract_wasm(wasm_hash);
-
-        MultiSig::mark_executed(&env, proposal_id);
-    }
-
-    /// Upgrades the contract to new WASM code (single admin version).
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `new_wasm_hash` - Hash of the uploaded WASM code (32 bytes)
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
         let start = env.ledger().timestamp();
 
@@ -930,8 +1023,6 @@ impl GrainlifyContract {
     /// # Panics
     /// * If admin address is not set (contract not initialized)
     /// * If caller is not the admin
-
-
     pub fn set_version(env: Env, new_version: u32) {
         let start = env.ledger().timestamp();
 
@@ -1360,7 +1451,7 @@ mod internal_test {
 
         // 6. Verify events emitted
         let events = env.events().all();
-        assert!(events.len() > 0);
+        assert!(!events.is_empty());
     }
 
     #[test]
@@ -54,7 +54,7 @@ pub struct MultiSig;
 impl MultiSig {
     /// Initialize multisig configuration
     pub fn init(env: &Env, signers: Vec<Address>, threshold: u32) {
-        if threshold == 0 || threshold > signers.len() as u32 {
+        if threshold == 0 || threshold > signers.len() {
             panic!("{:?}", MultiSigError::InvalidThreshold);
         }
 
@@ -161,9 +161,9 @@ impl MultiSig {
         );
     }
 
-    /// =======================
-    /// Internal Helpers
-    /// =======================
+    // =======================
+    // Internal Helpers
+    // =======================
 
     fn get_config(env: &Env) -> MultiSigConfig {
         env.storage()
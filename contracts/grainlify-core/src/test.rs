@@ -1,7 +1,21 @@
 #![cfg(test)]
 
-use crate::{GrainlifyContract, GrainlifyContractClient, GovernanceConfig, VotingScheme, VoteType, ProposalStatus};
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, BytesN, symbol_short};
+use crate::{GrainlifyContract, GrainlifyContractClient, GovernanceConfig, ProposalAction, ContractCall, VotingScheme, VoteType, Proposal, ProposalStatus, GovError, Pagination, MAX_EXECUTION_DELAY, MIN_VOTING_PERIOD};
+use soroban_sdk::{contract, contractimpl, testutils::{Address as _, Events, Ledger}, token, Address, Env, BytesN, IntoVal, symbol_short};
+
+fn create_governance_token<'a>(env: &Env, admin: &Address) -> (Address, token::StellarAssetClient<'a>) {
+    let address = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let client = token::StellarAssetClient::new(env, &address);
+    (address, client)
+}
+
+fn read_proposal(env: &Env, contract_id: &Address, proposal_id: u32) -> Proposal {
+    env.as_contract(contract_id, || {
+        let proposals: soroban_sdk::Map<u32, Proposal> =
+            env.storage().instance().get(&crate::governance::PROPOSALS).unwrap();
+        proposals.get(proposal_id).unwrap()
+    })
+}
 
 #[test]
 fn test_governance_full_flow() {
@@ -16,21 +30,32 @@ fn test_governance_full_flow() {
     let voter1 = Address::generate(&env);
     let voter2 = Address::generate(&env);
 
+    let (gov_token, gov_token_admin) = create_governance_token(&env, &admin);
     let config = GovernanceConfig {
         voting_period: 3600, // 1 hour
         execution_delay: 1800, // 30 mins
+        execution_window: 7 * 24 * 60 * 60,
         quorum_percentage: 5000, // 50%
         approval_threshold: 6000, // 60%
         min_proposal_stake: 10,
         voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
     };
 
+    // Proposer needs the minimum stake
+    gov_token_admin.mint(&proposer, &10);
+
     // Initialize
     client.init_governance(&admin, &config);
+    client.register_voter(&voter1);
+    client.register_voter(&voter2);
 
     // Create proposal
     let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
-    let proposal_id = client.create_proposal(&proposer, &wasm_hash, &symbol_short!("TEST"));
+    let proposal_id = client.create_proposal(&proposer, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("TEST"), &None, &None);
     assert_eq!(proposal_id, 0);
 
     // Cast votes
@@ -62,19 +87,26 @@ fn test_governance_approval_and_execution() {
     let admin = Address::generate(&env);
     let voter1 = Address::generate(&env);
 
+    let (gov_token, _) = create_governance_token(&env, &admin);
     let config = GovernanceConfig {
         voting_period: 3600,
         execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
         quorum_percentage: 10, // Very low for testing
         approval_threshold: 5000,
         min_proposal_stake: 0,
         voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: true,
+        total_voting_supply: 1000,
+        guardian: None,
     };
 
     client.init_governance(&admin, &config);
+    client.register_voter(&voter1);
 
-    let wasm_hash = BytesN::from_array(&env, &[2u8; 32]);
-    let proposal_id = client.create_proposal(&admin, &wasm_hash, &symbol_short!("UPGRADE"));
+    let wasm_hash = env.deployer().upload_contract_wasm(PING_WASM);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("UPGRADE"), &None, &None);
 
     client.cast_vote(&voter1, &proposal_id, &VoteType::For);
 
@@ -93,3 +125,1238 @@ fn test_governance_approval_and_execution() {
     // Execute
     client.execute_proposal(&voter1, &proposal_id);
 }
+
+#[test]
+fn test_execution_window_is_configurable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let voter = Address::generate(&env);
+
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 600,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 0,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+    client.register_voter(&voter);
+
+    let target_id = env.register_contract(None, FeeTarget);
+    let call = ProposalAction::CallContract(ContractCall {
+        contract: target_id,
+        function: soroban_sdk::Symbol::new(&env, "set_fee_rate"),
+        args: (contract_id.clone(), 250_i128).into_val(&env),
+    });
+    let wasm_hash = BytesN::from_array(&env, &[15u8; 32]);
+    let on_time = client.create_proposal(&admin, &call, &symbol_short!("ONTIME"), &None, &None);
+    let late = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("LATE"), &None, &None);
+    client.cast_vote(&voter, &on_time, &VoteType::For);
+    client.cast_vote(&voter, &late, &VoteType::For);
+
+    env.ledger().set_timestamp(3601);
+    assert_eq!(client.finalize_proposal(&on_time), ProposalStatus::Approved);
+    assert_eq!(client.finalize_proposal(&late), ProposalStatus::Approved);
+
+    // Executable until voting_end + execution_delay + execution_window
+    env.ledger().set_timestamp(3600 + 1800 + 600);
+    assert_eq!(client.try_expire_proposal(&late), Err(Ok(GovError::ProposalNotExpired)));
+    client.execute_proposal(&voter, &on_time);
+    assert_eq!(read_proposal(&env, &contract_id, on_time).status, ProposalStatus::Executed);
+
+    env.ledger().set_timestamp(3600 + 1800 + 601);
+    let res = client.try_execute_proposal(&voter, &late);
+    assert_eq!(res, Err(Ok(GovError::ProposalExpired)));
+
+    client.expire_proposal(&late);
+    assert_eq!(read_proposal(&env, &contract_id, late).status, ProposalStatus::Expired);
+    let res = client.try_execute_proposal(&voter, &late);
+    assert_eq!(res, Err(Ok(GovError::ProposalNotApproved)));
+}
+
+#[test]
+fn test_guardian_veto_blocks_execution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let voter = Address::generate(&env);
+
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: true,
+        total_voting_supply: 0,
+        guardian: Some(guardian.clone()),
+    };
+    client.init_governance(&admin, &config);
+    client.register_voter(&voter);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(PING_WASM);
+    let malicious = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("EVIL"), &None, &None);
+    let benign = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("FINE"), &None, &None);
+    client.cast_vote(&voter, &malicious, &VoteType::For);
+    client.cast_vote(&voter, &benign, &VoteType::For);
+
+    // Only approved proposals can be vetoed
+    assert_eq!(client.try_veto_proposal(&malicious), Err(Ok(GovError::ProposalNotApproved)));
+
+    env.ledger().set_timestamp(3601);
+    assert_eq!(client.finalize_proposal(&malicious), ProposalStatus::Approved);
+    assert_eq!(client.finalize_proposal(&benign), ProposalStatus::Approved);
+
+    client.veto_proposal(&malicious);
+    assert_eq!(env.auths()[0].0, guardian);
+    assert_eq!(read_proposal(&env, &contract_id, malicious).status, ProposalStatus::Vetoed);
+
+    // Once the execution delay has run out the veto window is closed
+    env.ledger().set_timestamp(3600 + 1800);
+    assert_eq!(client.try_veto_proposal(&benign), Err(Ok(GovError::VetoWindowClosed)));
+
+    let res = client.try_execute_proposal(&voter, &malicious);
+    assert_eq!(res, Err(Ok(GovError::ProposalNotApproved)));
+    client.execute_proposal(&voter, &benign);
+}
+
+#[test]
+fn test_init_governance_rejects_short_voting_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let mut config = GovernanceConfig {
+        voting_period: 0,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 5000,
+        approval_threshold: 6000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+
+    let res = client.try_init_governance(&admin, &config);
+    assert_eq!(res, Err(Ok(GovError::VotingPeriodTooShort)));
+
+    config.voting_period = MIN_VOTING_PERIOD - 1;
+    let res = client.try_init_governance(&admin, &config);
+    assert_eq!(res, Err(Ok(GovError::VotingPeriodTooShort)));
+}
+
+#[test]
+fn test_init_governance_bounds_execution_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let mut config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: MAX_EXECUTION_DELAY + 1,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 5000,
+        approval_threshold: 6000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+
+    let res = client.try_init_governance(&admin, &config);
+    assert_eq!(res, Err(Ok(GovError::ExecutionDelayTooLong)));
+
+    config.execution_delay = MAX_EXECUTION_DELAY;
+    client.init_governance(&admin, &config);
+}
+
+#[test]
+fn test_init_governance_accepts_min_voting_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let proposer = Address::generate(&env);
+
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: MIN_VOTING_PERIOD,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 5000,
+        approval_threshold: 6000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+
+    client.init_governance(&admin, &config);
+
+    let wasm_hash = BytesN::from_array(&env, &[3u8; 32]);
+    let proposal_id = client.create_proposal(&proposer, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("TEST"), &None, &None);
+
+    // Voting is still open right after creation
+    let res = client.try_finalize_proposal(&proposal_id);
+    assert_eq!(res, Err(Ok(GovError::VotingStillActive)));
+}
+
+#[test]
+fn test_proposal_quorum_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let voter1 = Address::generate(&env);
+    let voter2 = Address::generate(&env);
+
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 2000, // One of five registered voters meets the global quorum
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+
+    client.init_governance(&admin, &config);
+    client.register_voter(&voter1);
+    client.register_voter(&voter2);
+    for _ in 0..3 {
+        client.register_voter(&Address::generate(&env));
+    }
+
+    // Overrides below the global config are rejected
+    let wasm_hash = BytesN::from_array(&env, &[4u8; 32]);
+    let res = client.try_create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("LOW"), &Some(1000), &None);
+    assert_eq!(res, Err(Ok(GovError::InvalidThreshold)));
+    let res = client.try_create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("LOW"), &None, &Some(4000));
+    assert_eq!(res, Err(Ok(GovError::InvalidThreshold)));
+
+    // High-impact proposal demanding two votes' worth of turnout
+    let strict = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("STRICT"), &Some(4000), &None);
+    let normal = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("NORMAL"), &None, &None);
+
+    client.cast_vote(&voter1, &strict, &VoteType::For);
+    client.cast_vote(&voter1, &normal, &VoteType::For);
+
+    env.ledger().set_timestamp(3602);
+
+    // Same turnout: the global quorum is met, the override is not
+    assert_eq!(client.finalize_proposal(&normal), ProposalStatus::Approved);
+    assert_eq!(client.finalize_proposal(&strict), ProposalStatus::Rejected);
+
+    // With a second voter the override quorum is met
+    env.ledger().set_timestamp(4000);
+    let strict = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("STRICT"), &Some(4000), &None);
+    client.cast_vote(&voter1, &strict, &VoteType::For);
+    client.cast_vote(&voter2, &strict, &VoteType::For);
+
+    env.ledger().set_timestamp(4000 + 3602);
+    assert_eq!(client.finalize_proposal(&strict), ProposalStatus::Approved);
+}
+
+#[test]
+fn test_token_weighted_voting_uses_token_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let voter1 = Address::generate(&env);
+    let voter2 = Address::generate(&env);
+
+    let (gov_token, gov_token_admin) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 50,
+        voting_scheme: VotingScheme::TokenWeighted,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    gov_token_admin.mint(&voter1, &300);
+    gov_token_admin.mint(&voter2, &700);
+    client.checkpoint_voting_power(&voter1);
+    client.checkpoint_voting_power(&voter2);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 1);
+
+    // Proposer without any tokens can't meet the stake
+    let wasm_hash = BytesN::from_array(&env, &[5u8; 32]);
+    let res = client.try_create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("STAKE"), &None, &None);
+    assert_eq!(res, Err(Ok(GovError::InsufficientStake)));
+
+    let proposal_id = client.create_proposal(&voter1, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("WEIGHT"), &None, &None);
+    client.cast_vote(&voter1, &proposal_id, &VoteType::For);
+    client.cast_vote(&voter2, &proposal_id, &VoteType::Against);
+
+    let proposal = read_proposal(&env, &contract_id, proposal_id);
+    assert_eq!(proposal.votes_for, 300);
+    assert_eq!(proposal.votes_against, 700);
+
+    // 30% approval is below the 50% threshold
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Rejected);
+}
+
+#[test]
+fn test_cast_vote_counts_each_voter_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let voter1 = Address::generate(&env);
+    let voter2 = Address::generate(&env);
+    let voter3 = Address::generate(&env);
+
+    let (gov_token, gov_token_admin) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::TokenWeighted,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    gov_token_admin.mint(&voter1, &100);
+    gov_token_admin.mint(&voter2, &250);
+    gov_token_admin.mint(&voter3, &40);
+    client.checkpoint_voting_power(&voter1);
+    client.checkpoint_voting_power(&voter2);
+    client.checkpoint_voting_power(&voter3);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 1);
+
+    let wasm_hash = BytesN::from_array(&env, &[6u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("ONCE"), &None, &None);
+
+    client.cast_vote(&voter1, &proposal_id, &VoteType::For);
+    client.cast_vote(&voter2, &proposal_id, &VoteType::For);
+    client.cast_vote(&voter3, &proposal_id, &VoteType::Abstain);
+
+    // Second votes are rejected whatever the vote type
+    let res = client.try_cast_vote(&voter1, &proposal_id, &VoteType::For);
+    assert_eq!(res, Err(Ok(GovError::AlreadyVoted)));
+    let res = client.try_cast_vote(&voter2, &proposal_id, &VoteType::Against);
+    assert_eq!(res, Err(Ok(GovError::AlreadyVoted)));
+
+    let proposal = read_proposal(&env, &contract_id, proposal_id);
+    assert_eq!(proposal.votes_for, 100 + 250);
+    assert_eq!(proposal.votes_against, 0);
+    assert_eq!(proposal.votes_abstain, 40);
+    assert_eq!(proposal.total_votes, 3);
+}
+
+#[test]
+fn test_change_vote_moves_voting_power() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let voter = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let (gov_token, gov_token_admin) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::TokenWeighted,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    gov_token_admin.mint(&voter, &300);
+    gov_token_admin.mint(&other, &100);
+    client.checkpoint_voting_power(&voter);
+    client.checkpoint_voting_power(&other);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 1);
+
+    let wasm_hash = BytesN::from_array(&env, &[13u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("SWITCH"), &None, &None);
+
+    // Only voters who already voted can change
+    let res = client.try_change_vote(&voter, &proposal_id, &VoteType::Against);
+    assert_eq!(res, Err(Ok(GovError::NotVoted)));
+
+    client.cast_vote(&voter, &proposal_id, &VoteType::For);
+    client.cast_vote(&other, &proposal_id, &VoteType::For);
+    client.change_vote(&voter, &proposal_id, &VoteType::Against);
+    assert_eq!(env.auths()[0].0, voter);
+
+    let proposal = read_proposal(&env, &contract_id, proposal_id);
+    assert_eq!(proposal.votes_for, 100);
+    assert_eq!(proposal.votes_against, 300);
+    assert_eq!(proposal.votes_abstain, 0);
+    assert_eq!(proposal.total_votes, 2);
+
+    // Blocked once voting has ended
+    env.ledger().set_timestamp(3601);
+    let res = client.try_change_vote(&voter, &proposal_id, &VoteType::For);
+    assert_eq!(res, Err(Ok(GovError::VotingEnded)));
+
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Rejected);
+}
+
+#[test]
+fn test_voting_power_uses_snapshot_before_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let voter = Address::generate(&env);
+    let late_buyer = Address::generate(&env);
+
+    let (gov_token, gov_token_admin) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::TokenWeighted,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    env.ledger().set_sequence_number(10);
+    gov_token_admin.mint(&voter, &100);
+    client.checkpoint_voting_power(&voter);
+
+    env.ledger().set_sequence_number(11);
+    let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("SNAP"), &None, &None);
+    assert_eq!(read_proposal(&env, &contract_id, proposal_id).snapshot_ledger, 11);
+
+    // Balances acquired after the proposal opened don't count
+    env.ledger().set_sequence_number(12);
+    gov_token_admin.mint(&voter, &900);
+    gov_token_admin.mint(&late_buyer, &5000);
+    client.checkpoint_voting_power(&voter);
+    client.checkpoint_voting_power(&late_buyer);
+
+    client.cast_vote(&voter, &proposal_id, &VoteType::For);
+    client.cast_vote(&late_buyer, &proposal_id, &VoteType::Against);
+
+    let proposal = read_proposal(&env, &contract_id, proposal_id);
+    assert_eq!(proposal.votes_for, 100);
+    assert_eq!(proposal.votes_against, 0);
+}
+
+#[test]
+fn test_moved_tokens_are_not_counted_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    let (gov_token, gov_token_admin) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::TokenWeighted,
+        governance_token: gov_token.clone(),
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    // Both checkpoints cover the same 100 tokens
+    env.ledger().set_sequence_number(10);
+    gov_token_admin.mint(&first, &100);
+    client.checkpoint_voting_power(&first);
+    token::Client::new(&env, &gov_token).transfer(&first, &second, &100);
+    client.checkpoint_voting_power(&second);
+
+    env.ledger().set_sequence_number(11);
+    let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("MOVE"), &None, &None);
+
+    client.cast_vote(&first, &proposal_id, &VoteType::For);
+    client.cast_vote(&second, &proposal_id, &VoteType::For);
+
+    let proposal = read_proposal(&env, &contract_id, proposal_id);
+    assert_eq!(proposal.votes_for, 100);
+}
+
+#[test]
+fn test_tokens_cannot_be_voted_again_after_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    let (gov_token, gov_token_admin) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::TokenWeighted,
+        governance_token: gov_token.clone(),
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+    let gov_token_client = token::Client::new(&env, &gov_token);
+
+    // The same 100 tokens pass through both voters before the snapshot
+    env.ledger().set_sequence_number(10);
+    gov_token_admin.mint(&first, &100);
+    client.checkpoint_voting_power(&first);
+    assert_eq!(env.auths()[0].0, first);
+    gov_token_client.transfer(&first, &second, &100);
+    client.checkpoint_voting_power(&second);
+    gov_token_client.transfer(&second, &first, &100);
+
+    env.ledger().set_sequence_number(11);
+    let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("RECYCLE"), &None, &None);
+    let other_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("OTHER"), &None, &None);
+
+    // Voting escrows the tokens, so they can't be handed over mid-vote
+    client.cast_vote(&first, &proposal_id, &VoteType::For);
+    assert_eq!(gov_token_client.balance(&first), 0);
+    assert!(gov_token_client.try_transfer(&first, &second, &100).is_err());
+    client.cast_vote(&second, &proposal_id, &VoteType::For);
+    assert_eq!(read_proposal(&env, &contract_id, proposal_id).votes_for, 100);
+
+    // The escrowed tokens still vote on other open proposals
+    client.cast_vote(&first, &other_id, &VoteType::Against);
+    assert_eq!(read_proposal(&env, &contract_id, other_id).votes_against, 100);
+    assert_eq!(client.get_vote_lock(&first).unwrap().amount, 100);
+
+    let res = client.try_withdraw_vote_lock(&first);
+    assert_eq!(res, Err(Ok(GovError::TokensLocked)));
+
+    env.ledger().set_timestamp(3601);
+    assert_eq!(client.withdraw_vote_lock(&first), 100);
+    assert_eq!(gov_token_client.balance(&first), 100);
+    assert_eq!(client.get_vote_lock(&first), None);
+}
+
+// Minimal contract exporting `ping() -> u32` returning 7, with the
+// contractenvmetav0 section the host requires (interface version 21).
+const PING_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7e,
+    0x03, 0x02, 0x01, 0x00, 0x07, 0x08, 0x01, 0x04, 0x70, 0x69, 0x6e, 0x67, 0x00, 0x00, 0x0a,
+    0x0b, 0x01, 0x09, 0x00, 0x42, 0x84, 0x80, 0x80, 0x80, 0xf0, 0x00, 0x0b, 0x00, 0x1e, 0x11,
+    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x65, 0x6e, 0x76, 0x6d, 0x65, 0x74, 0x61,
+    0x76, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00,
+];
+
+#[test]
+fn test_execute_proposal_upgrades_wasm() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let voter = Address::generate(&env);
+
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: true,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+    client.register_voter(&voter);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(PING_WASM);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("UPGRADE"), &None, &None);
+    client.cast_vote(&voter, &proposal_id, &VoteType::For);
+
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Approved);
+
+    env.ledger().set_timestamp(3602 + 1801);
+    client.execute_proposal(&voter, &proposal_id);
+
+    // The contract now runs the new code
+    let pong: u32 = env.invoke_contract(&contract_id, &soroban_sdk::Symbol::new(&env, "ping"), soroban_sdk::vec![&env]);
+    assert_eq!(pong, 7);
+}
+
+#[test]
+fn test_execute_proposal_with_missing_wasm_reverts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let voter = Address::generate(&env);
+
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: true,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+    client.register_voter(&voter);
+
+    // Hash that was never uploaded
+    let wasm_hash = BytesN::from_array(&env, &[8u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("MISSING"), &None, &None);
+    client.cast_vote(&voter, &proposal_id, &VoteType::For);
+
+    env.ledger().set_timestamp(3602);
+    client.finalize_proposal(&proposal_id);
+
+    env.ledger().set_timestamp(3602 + 1801);
+    assert!(client.try_execute_proposal(&voter, &proposal_id).is_err());
+    assert_eq!(read_proposal(&env, &contract_id, proposal_id).status, ProposalStatus::Approved);
+}
+
+#[test]
+fn test_execute_proposal_rejects_upgrade_when_disabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let voter = Address::generate(&env);
+
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 0,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+    client.register_voter(&voter);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(PING_WASM);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("UPGRADE"), &None, &None);
+    client.cast_vote(&voter, &proposal_id, &VoteType::For);
+
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Approved);
+
+    // Nothing is swapped, so the proposal isn't reported as executed
+    env.ledger().set_timestamp(3602 + 1801);
+    let res = client.try_execute_proposal(&voter, &proposal_id);
+    assert_eq!(res, Err(Ok(GovError::UpgradesDisabled)));
+    assert_eq!(read_proposal(&env, &contract_id, proposal_id).status, ProposalStatus::Approved);
+}
+
+#[test]
+fn test_token_weighted_quorum_uses_total_voting_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let small_holder = Address::generate(&env);
+    let large_holder = Address::generate(&env);
+
+    let (gov_token, gov_token_admin) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 5000, // Half of the supply must vote
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::TokenWeighted,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    gov_token_admin.mint(&small_holder, &400);
+    gov_token_admin.mint(&large_holder, &600);
+    client.checkpoint_voting_power(&small_holder);
+    client.checkpoint_voting_power(&large_holder);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 1);
+
+    let wasm_hash = BytesN::from_array(&env, &[9u8; 32]);
+    let below = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("BELOW"), &None, &None);
+    let above = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("ABOVE"), &None, &None);
+
+    // 400 of 1000 is under the 50% quorum, 600 of 1000 is over it
+    client.cast_vote(&small_holder, &below, &VoteType::For);
+    client.cast_vote(&large_holder, &above, &VoteType::For);
+
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.finalize_proposal(&below), ProposalStatus::Rejected);
+    assert_eq!(client.finalize_proposal(&above), ProposalStatus::Approved);
+}
+
+#[test]
+fn test_finalize_event_carries_tallies() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let voter1 = Address::generate(&env);
+    let voter2 = Address::generate(&env);
+    let voter3 = Address::generate(&env);
+
+    let (gov_token, gov_token_admin) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 1000,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::TokenWeighted,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    gov_token_admin.mint(&voter1, &300);
+    gov_token_admin.mint(&voter2, &100);
+    gov_token_admin.mint(&voter3, &50);
+    client.checkpoint_voting_power(&voter1);
+    client.checkpoint_voting_power(&voter2);
+    client.checkpoint_voting_power(&voter3);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 1);
+
+    let wasm_hash = BytesN::from_array(&env, &[16u8; 32]);
+    let voted = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("VOTED"), &None, &None);
+    let ignored = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("IGNORED"), &None, &None);
+    client.cast_vote(&voter1, &voted, &VoteType::For);
+    client.cast_vote(&voter2, &voted, &VoteType::Against);
+    client.cast_vote(&voter3, &voted, &VoteType::Abstain);
+
+    env.ledger().set_timestamp(3601);
+    assert_eq!(client.finalize_proposal(&voted), ProposalStatus::Approved);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("finalize"), voted).into_val(&env));
+    let payload: (ProposalStatus, i128, i128, i128, u32, bool) = data.into_val(&env);
+    assert_eq!(payload, (ProposalStatus::Approved, 300, 100, 50, 3, true));
+
+    // Rejections for missing quorum are reported too
+    assert_eq!(client.finalize_proposal(&ignored), ProposalStatus::Rejected);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("finalize"), ignored).into_val(&env));
+    let payload: (ProposalStatus, i128, i128, i128, u32, bool) = data.into_val(&env);
+    assert_eq!(payload, (ProposalStatus::Rejected, 0, 0, 0, 0, false));
+}
+
+#[test]
+fn test_abstentions_count_for_quorum_but_not_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let supporter = Address::generate(&env);
+    let opponent = Address::generate(&env);
+    let abstainer = Address::generate(&env);
+
+    // Quorum: cast votes (For + Against + Abstain) / supply >= 50%
+    // Approval: For / (For + Against) >= 60%, abstentions ignored
+    let (gov_token, gov_token_admin) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 5000,
+        approval_threshold: 6000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::TokenWeighted,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 1000,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    gov_token_admin.mint(&supporter, &300);
+    gov_token_admin.mint(&opponent, &100);
+    gov_token_admin.mint(&abstainer, &200);
+    client.checkpoint_voting_power(&supporter);
+    client.checkpoint_voting_power(&opponent);
+    client.checkpoint_voting_power(&abstainer);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 1);
+
+    let wasm_hash = BytesN::from_array(&env, &[19u8; 32]);
+    let with_abstain = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("WITH"), &None, &None);
+    let without_abstain = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("WITHOUT"), &None, &None);
+    let reversed = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("REVERSED"), &None, &None);
+
+    client.cast_vote(&supporter, &with_abstain, &VoteType::For);
+    client.cast_vote(&opponent, &with_abstain, &VoteType::Against);
+    client.cast_vote(&abstainer, &with_abstain, &VoteType::Abstain);
+
+    client.cast_vote(&supporter, &without_abstain, &VoteType::For);
+    client.cast_vote(&opponent, &without_abstain, &VoteType::Against);
+
+    client.cast_vote(&supporter, &reversed, &VoteType::Against);
+    client.cast_vote(&opponent, &reversed, &VoteType::For);
+    client.cast_vote(&abstainer, &reversed, &VoteType::Abstain);
+
+    env.ledger().set_timestamp(3601);
+
+    // 600/1000 turnout meets quorum only thanks to the abstention; 300/400 For passes
+    assert_eq!(client.finalize_proposal(&with_abstain), ProposalStatus::Approved);
+
+    // Same For/Against split without the abstention: 400/1000 misses quorum
+    assert_eq!(client.finalize_proposal(&without_abstain), ProposalStatus::Rejected);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let payload: (ProposalStatus, i128, i128, i128, u32, bool) = data.into_val(&env);
+    assert_eq!(payload, (ProposalStatus::Rejected, 300, 100, 0, 2, false));
+
+    // Quorum met, but abstentions don't count as support: 100/400 For fails
+    assert_eq!(client.finalize_proposal(&reversed), ProposalStatus::Rejected);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let payload: (ProposalStatus, i128, i128, i128, u32, bool) = data.into_val(&env);
+    assert_eq!(payload, (ProposalStatus::Rejected, 100, 300, 200, 3, true));
+}
+
+#[test]
+fn test_one_person_one_vote_quorum_uses_registered_voters() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 5000,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 0,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    let mut voters = soroban_sdk::Vec::new(&env);
+    for _ in 0..4 {
+        let voter = Address::generate(&env);
+        client.register_voter(&voter);
+        voters.push_back(voter);
+    }
+    assert_eq!(client.get_voter_count(), 4);
+    assert_eq!(
+        client.try_register_voter(&voters.get(0).unwrap()),
+        Err(Ok(GovError::AlreadyRegistered))
+    );
+
+    let wasm_hash = BytesN::from_array(&env, &[10u8; 32]);
+    let below = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("BELOW"), &None, &None);
+    let above = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), &symbol_short!("ABOVE"), &None, &None);
+
+    // 1 of 4 voters is 25% turnout, 3 of 4 is 75%
+    client.cast_vote(&voters.get(0).unwrap(), &below, &VoteType::For);
+    for voter in voters.iter().take(3) {
+        client.cast_vote(&voter, &above, &VoteType::For);
+    }
+
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.finalize_proposal(&below), ProposalStatus::Rejected);
+    assert_eq!(client.finalize_proposal(&above), ProposalStatus::Approved);
+}
+
+#[test]
+fn test_closed_enrollment_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 5000,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 0,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    // Open enrollment: voters sign up themselves
+    assert!(client.is_open_enrollment());
+    let self_registered = Address::generate(&env);
+    client.register_voter(&self_registered);
+    assert_eq!(env.auths()[0].0, self_registered);
+
+    client.set_open_enrollment(&false);
+    assert_eq!(env.auths()[0].0, admin);
+    assert!(!client.is_open_enrollment());
+
+    // Closed enrollment: the admin signs instead
+    let enrolled = Address::generate(&env);
+    client.register_voter(&enrolled);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_voter_count(), 2);
+    assert!(client.is_registered_voter(&enrolled));
+
+    // Unregistered addresses can't vote under OnePersonOneVote
+    let outsider = Address::generate(&env);
+    let wasm_hash = BytesN::from_array(&env, &[14u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("ENROLL"), &None, &None);
+    let res = client.try_cast_vote(&outsider, &proposal_id, &VoteType::For);
+    assert_eq!(res, Err(Ok(GovError::VoterNotRegistered)));
+
+    // 1 of 2 registered voters meets the 50% quorum
+    client.cast_vote(&enrolled, &proposal_id, &VoteType::For);
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Approved);
+}
+
+#[test]
+fn test_get_and_list_proposals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 5000,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 0,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    let wasm_hash = BytesN::from_array(&env, &[18u8; 32]);
+    let descriptions = [symbol_short!("P0"), symbol_short!("P1"), symbol_short!("P2"), symbol_short!("P3")];
+    for description in descriptions.iter() {
+        client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash.clone()), description, &None, &None);
+    }
+    client.cancel_proposal(&1);
+
+    let proposal = client.get_proposal(&2);
+    assert_eq!(proposal.id, 2);
+    assert_eq!(proposal.description, symbol_short!("P2"));
+    assert_eq!(proposal.status, ProposalStatus::Active);
+    assert!(matches!(client.try_get_proposal(&4), Err(Ok(GovError::ProposalNotFound))));
+
+    let all = client.list_proposals(&None, &Pagination { start_index: 0, limit: 10 });
+    assert_eq!(all.len(), 4);
+
+    let active = client.list_proposals(&Some(ProposalStatus::Active), &Pagination { start_index: 0, limit: 10 });
+    assert_eq!(active.len(), 3);
+    assert_eq!(active.get(0).unwrap().id, 0);
+    assert_eq!(active.get(1).unwrap().id, 2);
+    assert_eq!(active.get(2).unwrap().id, 3);
+
+    // The page window applies to the filtered list
+    let page = client.list_proposals(&Some(ProposalStatus::Active), &Pagination { start_index: 1, limit: 1 });
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 2);
+
+    let cancelled = client.list_proposals(&Some(ProposalStatus::Cancelled), &Pagination { start_index: 0, limit: 10 });
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled.get(0).unwrap().id, 1);
+}
+
+#[test]
+fn test_proposer_can_cancel_active_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let proposer = Address::generate(&env);
+    let voter = Address::generate(&env);
+
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 0,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+    client.register_voter(&voter);
+
+    let wasm_hash = BytesN::from_array(&env, &[11u8; 32]);
+    let proposal_id = client.create_proposal(&proposer, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("OOPS"), &None, &None);
+    client.cast_vote(&voter, &proposal_id, &VoteType::For);
+
+    client.cancel_proposal(&proposal_id);
+    assert_eq!(env.auths()[0].0, proposer);
+    assert_eq!(read_proposal(&env, &contract_id, proposal_id).status, ProposalStatus::Cancelled);
+
+    // Cancelled is terminal
+    assert_eq!(client.try_cancel_proposal(&proposal_id), Err(Ok(GovError::ProposalNotActive)));
+    let res = client.try_cast_vote(&Address::generate(&env), &proposal_id, &VoteType::For);
+    assert_eq!(res, Err(Ok(GovError::ProposalNotActive)));
+
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.try_finalize_proposal(&proposal_id), Err(Ok(GovError::ProposalNotActive)));
+    env.ledger().set_timestamp(3602 + 1801);
+    assert_eq!(client.try_execute_proposal(&voter, &proposal_id), Err(Ok(GovError::ProposalNotApproved)));
+}
+
+#[test]
+fn test_cancel_proposal_after_voting_end() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 0,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+
+    let wasm_hash = BytesN::from_array(&env, &[12u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::UpgradeWasm(wasm_hash), &symbol_short!("LATE"), &None, &None);
+
+    env.ledger().set_timestamp(3601);
+    assert_eq!(client.try_cancel_proposal(&proposal_id), Err(Ok(GovError::VotingEnded)));
+}
+
+/// Stand-in for a contract governance reconfigures, e.g. an escrow fee rate.
+#[contract]
+struct FeeTarget;
+
+#[contractimpl]
+impl FeeTarget {
+    pub fn set_fee_rate(env: Env, caller: Address, rate: i128) {
+        caller.require_auth();
+        env.storage().instance().set(&symbol_short!("fee"), &rate);
+    }
+
+    pub fn fee_rate(env: Env) -> i128 {
+        env.storage().instance().get(&symbol_short!("fee")).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_execute_proposal_calls_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &contract_id);
+    let target_id = env.register_contract(None, FeeTarget);
+    let target = FeeTargetClient::new(&env, &target_id);
+
+    let admin = Address::generate(&env);
+    let voter = Address::generate(&env);
+
+    let (gov_token, _) = create_governance_token(&env, &admin);
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        execution_window: 7 * 24 * 60 * 60,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: gov_token,
+        execute_upgrades: false,
+        total_voting_supply: 0,
+        guardian: None,
+    };
+    client.init_governance(&admin, &config);
+    client.register_voter(&voter);
+
+    let action = ProposalAction::CallContract(ContractCall {
+        contract: target_id.clone(),
+        function: soroban_sdk::Symbol::new(&env, "set_fee_rate"),
+        args: (contract_id.clone(), 250_i128).into_val(&env),
+    });
+    let proposal_id = client.create_proposal(&admin, &action, &symbol_short!("FEE"), &None, &None);
+    client.cast_vote(&voter, &proposal_id, &VoteType::For);
+
+    env.ledger().set_timestamp(3602);
+    client.finalize_proposal(&proposal_id);
+    assert_eq!(target.fee_rate(), 0);
+
+    env.ledger().set_timestamp(3602 + 1801);
+    client.execute_proposal(&voter, &proposal_id);
+
+    assert_eq!(target.fee_rate(), 250);
+    assert_eq!(read_proposal(&env, &contract_id, proposal_id).status, ProposalStatus::Executed);
+}
@@ -100,8 +100,9 @@
 //! );
 //!
 //! // 2. Lock prize pool (10,000 USDC)
+//! let organizer = Address::from_string("GORGANIZER...");
 //! let prize_pool = 10_000_0000000; // 10,000 USDC (7 decimals)
-//! escrow_client.lock_program_funds(&prize_pool);
+//! escrow_client.lock_program_funds(&program_id, &organizer, &prize_pool);
 //!
 //! // 3. After hackathon, distribute prizes
 //! let winners = vec![
@@ -118,7 +119,7 @@
 //!     2_000_0000000,  // 3rd place: 2,000 USDC
 //! ];
 //!
-//! escrow_client.batch_payout(&winners, &prizes);
+//! escrow_client.batch_payout(&program_id, &backend, &winners, &prizes);
 //! ```
 //!
 //! ## Event System
@@ -140,8 +141,8 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, token, vec, Address, Env, String, Symbol,
-    Vec,
+    contract, contractimpl, contracttype, symbol_short, token, vec, Address, Env, Map, String,
+    Symbol, Vec,
 };
 
 // Event types
@@ -150,6 +151,7 @@ const PROGRAM_INITIALIZED: Symbol = symbol_short!("ProgInit");
 const FUNDS_LOCKED: Symbol = symbol_short!("FundLock");
 const BATCH_PAYOUT: Symbol = symbol_short!("BatchPay");
 const PAYOUT: Symbol = symbol_short!("Payout");
+const FUNDS_WITHDRAWN: Symbol = symbol_short!("Withdraw");
 
 // Storage keys
 const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
@@ -264,6 +266,7 @@ mod monitoring {
     pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
         let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
         let time_key = (Symbol::new(env, "perf_time"), function.clone());
+        let last_key = (Symbol::new(env, "perf_last"), function.clone());
 
         let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
         let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
@@ -272,6 +275,9 @@ mod monitoring {
         env.storage()
             .persistent()
             .set(&time_key, &(total + duration));
+        env.storage()
+            .persistent()
+            .set(&last_key, &env.ledger().timestamp());
 
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("perf")),
@@ -344,7 +350,7 @@ mod monitoring {
         let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
         let last: u64 = env.storage().persistent().get(&last_key).unwrap_or(0);
 
-        let avg = if count > 0 { total / count } else { 0 };
+        let avg = total.checked_div(count).unwrap_or(0);
 
         PerformanceStats {
             function_name,
@@ -517,6 +523,7 @@ const PROGRAM_REGISTRY: Symbol = symbol_short!("ProgReg");
 /// * `recipient` - Address that received the payout
 /// * `amount` - Amount transferred (in token's smallest denomination)
 /// * `timestamp` - Unix timestamp when payout was executed
+/// * `schedule_id` - Release schedule that produced this payout (None for direct payouts)
 ///
 /// # Usage
 /// These records are stored in the payout history to provide a complete
@@ -528,6 +535,7 @@ const PROGRAM_REGISTRY: Symbol = symbol_short!("ProgReg");
 ///     recipient: winner_address,
 ///     amount: 1000_0000000, // 1000 USDC
 ///     timestamp: env.ledger().timestamp(),
+///     schedule_id: None,
 /// };
 /// ```
 #[contracttype]
@@ -536,6 +544,7 @@ pub struct PayoutRecord {
     pub recipient: Address,
     pub amount: i128,
     pub timestamp: u64,
+    pub schedule_id: Option<u64>,
 }
 
 /// Time-based release schedule for program funds.
@@ -629,16 +638,19 @@ pub struct ProgramScheduleReleased {
 /// * `program_id` - Unique identifier for the program/hackathon
 /// * `total_funds` - Total amount of funds locked (cumulative)
 /// * `remaining_balance` - Current available balance for payouts
+/// * `total_withdrawn` - Cumulative amount taken out by `withdraw_remaining`
 /// * `authorized_payout_key` - Address authorized to trigger payouts
 /// * `payout_history` - Complete record of all payouts
 /// * `token_address` - Token contract used for transfers
+/// * `deadline` - Optional timestamp after which payouts are rejected
+/// * `max_per_recipient` - Optional cap on the cumulative amount paid to one address
 ///
 /// # Storage
 /// Stored in instance storage with key `PROGRAM_DATA`.
 ///
 /// # Invariants
 /// - `remaining_balance <= total_funds` (always)
-/// - `remaining_balance = total_funds - sum(payout_history.amounts)`
+/// - `total_funds = remaining_balance + sum(payout_history.amounts) + total_withdrawn`
 /// - `payout_history` is append-only
 /// - `program_id` and `authorized_payout_key` are immutable after init
 ///
@@ -648,9 +660,12 @@ pub struct ProgramScheduleReleased {
 ///     program_id: String::from_str(&env, "Hackathon2024"),
 ///     total_funds: 10_000_0000000,
 ///     remaining_balance: 7_000_0000000,
+///     total_withdrawn: 0,
 ///     authorized_payout_key: backend_address,
 ///     payout_history: vec![&env],
 ///     token_address: usdc_token_address,
+///     deadline: None,
+///     max_per_recipient: None,
 /// };
 /// ```
 /// Complete program state and configuration.
@@ -660,7 +675,7 @@ pub struct ProgramScheduleReleased {
 ///
 /// # Invariants
 /// - `remaining_balance <= total_funds` (always)
-/// - `remaining_balance = total_funds - sum(payout_history.amounts)`
+/// - `total_funds = remaining_balance + sum(payout_history.amounts) + total_withdrawn`
 /// - `payout_history` is append-only
 /// - `program_id` and `authorized_payout_key` are immutable after registration
 #[contracttype]
@@ -669,9 +684,12 @@ pub struct ProgramData {
     pub program_id: String,
     pub total_funds: i128,
     pub remaining_balance: i128,
+    pub total_withdrawn: i128,
     pub authorized_payout_key: Address,
     pub payout_history: Vec<PayoutRecord>,
     pub token_address: Address,
+    pub deadline: Option<u64>,
+    pub max_per_recipient: Option<i128>,
 }
 
 /// Storage key type for individual programs
@@ -682,6 +700,9 @@ pub enum DataKey {
     ReleaseSchedule(String, u64), // program_id, schedule_id -> ProgramReleaseSchedule
     ReleaseHistory(String),       // program_id -> Vec<ProgramReleaseHistory>
     NextScheduleId(String),       // program_id -> next schedule_id
+    RecipientPaid(String),        // program_id -> Map<Address, i128> cumulative payouts
+    StrictBatch(String),          // program_id -> reject duplicate batch recipients
+    PayoutKeys(String),           // program_id -> Vec<Address> keys allowed to pay out
     IsPaused,                     // Global contract pause state
 }
 
@@ -915,9 +936,12 @@ impl ProgramEscrowContract {
             program_id: program_id.clone(),
             total_funds: 0,
             remaining_balance: 0,
+            total_withdrawn: 0,
             authorized_payout_key: authorized_payout_key.clone(),
             payout_history: vec![&env],
             token_address: token_address.clone(),
+            deadline: None,
+            max_per_recipient: None,
         };
 
         // Initialize fee config with zero fees (disabled by default)
@@ -969,6 +993,78 @@ impl ProgramEscrowContract {
             .unwrap_or(0)
     }
 
+    /// Check whether the program's deadline has passed (internal helper)
+    fn is_expired_internal(env: &Env, program_data: &ProgramData) -> bool {
+        match program_data.deadline {
+            Some(deadline) => env.ledger().timestamp() > deadline,
+            None => false,
+        }
+    }
+
+    /// Get the keys allowed to trigger payouts; the original
+    /// `authorized_payout_key` is always the first member (internal helper)
+    fn get_payout_keys_internal(
+        env: &Env,
+        program_id: &String,
+        program_data: &ProgramData,
+    ) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PayoutKeys(program_id.clone()))
+            .unwrap_or(vec![env, program_data.authorized_payout_key.clone()])
+    }
+
+    /// Require `caller` to be one of the program's payout keys and to have
+    /// authorized this call (internal helper)
+    fn require_payout_key(
+        env: &Env,
+        program_id: &String,
+        program_data: &ProgramData,
+        caller: &Address,
+    ) {
+        if !Self::get_payout_keys_internal(env, program_id, program_data).contains(caller) {
+            panic!("Unauthorized: caller is not a payout key");
+        }
+        caller.require_auth();
+    }
+
+    /// Check whether duplicate batch recipients are rejected (internal helper)
+    fn is_strict_batch_internal(env: &Env, program_id: &String) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::StrictBatch(program_id.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Get cumulative payouts per recipient (internal helper)
+    fn get_recipient_paid_internal(env: &Env, program_id: &String) -> Map<Address, i128> {
+        env.storage()
+            .instance()
+            .get(&DataKey::RecipientPaid(program_id.clone()))
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Add a payout to a recipient's running total, enforcing the program's
+    /// per-recipient cap (internal helper)
+    fn add_recipient_paid(
+        program_data: &ProgramData,
+        paid: &mut Map<Address, i128>,
+        recipient: &Address,
+        amount: i128,
+    ) {
+        let total = paid
+            .get(recipient.clone())
+            .unwrap_or(0)
+            .checked_add(amount)
+            .unwrap_or_else(|| panic!("Payout amount overflow"));
+        if let Some(cap) = program_data.max_per_recipient {
+            if total > cap {
+                panic!("Recipient cap exceeded");
+            }
+        }
+        paid.set(recipient.clone(), total);
+    }
+
     /// Get fee configuration (internal helper)
     fn get_fee_config_internal(env: &Env) -> FeeConfig {
         env.storage()
@@ -1023,6 +1119,8 @@ impl ProgramEscrowContract {
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `program_id` - The program to fund
+    /// * `from` - Address the tokens are pulled from (must authorize)
     /// * `amount` - Amount of tokens to lock (in token's smallest denomination)
     ///
     /// # Returns
@@ -1031,23 +1129,20 @@ impl ProgramEscrowContract {
     /// # Panics
     /// * If amount is zero or negative
     /// * If program is not initialized
+    /// * If `from` has insufficient token balance
     ///
     /// # State Changes
+    /// - Transfers `amount` tokens from `from` to the contract
     /// - Increases `total_funds` by amount
     /// - Increases `remaining_balance` by amount
     /// - Emits FundsLocked event
     ///
-    /// # Prerequisites
-    /// Before calling this function:
-    /// 1. Caller must have sufficient token balance
-    /// 2. Caller must approve contract for token transfer
-    /// 3. Tokens must actually be transferred to contract
+    /// # Authorization
+    /// - Requires authorization from `from`
     ///
     /// # Security Considerations
     /// - Amount must be positive
-    /// - This function doesn't perform the actual token transfer
-    /// - Caller is responsible for transferring tokens to contract
-    /// - Consider verifying contract balance matches recorded amount
+    /// - The token transfer and the balance update happen atomically
     /// - Multiple lock operations are additive (cumulative)
     ///
     /// # Events
@@ -1055,54 +1150,38 @@ impl ProgramEscrowContract {
     ///
     /// # Example
     /// ```rust
-    /// use soroban_sdk::token;
-    ///
-    /// // 1. Transfer tokens to contract
     /// let amount = 10_000_0000000; // 10,000 USDC
-    /// token_client.transfer(
-    ///     &organizer,
-    ///     &contract_address,
-    ///     &amount
-    /// );
-    ///
-    /// // 2. Record the locked funds
-    /// let updated = escrow_client.lock_program_funds(&amount);
+    /// let updated = escrow_client.lock_program_funds(&program_id, &organizer, &amount);
     /// println!("Locked: {} USDC", amount / 10_000_000);
     /// println!("Remaining: {}", updated.remaining_balance);
     /// ```
     ///
     /// # Production Usage
     /// ```bash
-    /// # 1. Transfer USDC to contract
-    /// stellar contract invoke \
-    ///   --id USDC_TOKEN_ID \
-    ///   --source ORGANIZER_KEY \
-    ///   -- transfer \
-    ///   --from ORGANIZER_ADDRESS \
-    ///   --to CONTRACT_ADDRESS \
-    ///   --amount 10000000000
-    ///
-    /// # 2. Record locked funds
     /// stellar contract invoke \
     ///   --id CONTRACT_ID \
     ///   --source ORGANIZER_KEY \
     ///   -- lock_program_funds \
+    ///   --program_id PROGRAM_ID \
+    ///   --from ORGANIZER_ADDRESS \
     ///   --amount 10000000000
     /// ```
     ///
     /// # Gas Cost
-    /// Low - Storage update + event emission
-    ///
-    /// # Common Pitfalls
-    /// - Forgetting to transfer tokens before calling
-    /// -  Locking amount that exceeds actual contract balance
-    /// -  Not verifying contract received the tokens
-    pub fn lock_program_funds(env: Env, program_id: String, amount: i128) -> ProgramData {
+    /// Low - Token transfer + storage update + event emission
+    pub fn lock_program_funds(
+        env: Env,
+        program_id: String,
+        from: Address,
+        amount: i128,
+    ) -> ProgramData {
+        from.require_auth();
+
         // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, env.current_contract_address());
+        anti_abuse::check_rate_limit(&env, from.clone());
 
         let _start = env.ledger().timestamp();
-        let caller = env.current_contract_address();
+        let caller = from.clone();
 
         // Check if contract is paused
         if Self::is_paused_internal(&env) {
@@ -1127,6 +1206,11 @@ impl ProgramEscrowContract {
                 panic!("Program not found")
             });
 
+        // Pull the tokens into escrow
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&from, &contract_address, &amount);
+
         // Calculate and collect fee if enabled
         let fee_config = Self::get_fee_config_internal(&env);
         let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
@@ -1177,6 +1261,8 @@ impl ProgramEscrowContract {
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `program_id` - The program to pay out from
+    /// * `caller` - One of the program's payout keys
     /// * `recipients` - Vector of recipient addresses
     /// * `amounts` - Vector of amounts (must match recipients length)
     ///
@@ -1188,13 +1274,16 @@ impl ProgramEscrowContract {
     /// * If program is not initialized
     /// * If recipients and amounts vectors have different lengths
     /// * If vectors are empty
+    /// * If strict batch mode is on and a recipient appears more than once
     /// * If any amount is zero or negative
     /// * If total payout exceeds remaining balance
     /// * If arithmetic overflow occurs
+    /// * If the program deadline has passed
+    /// * If a recipient's cumulative payouts would exceed `max_per_recipient`
     ///
     /// # Authorization
-    /// - **CRITICAL**: Only authorized payout key can call
-    /// - Caller must be exact match to `authorized_payout_key`
+    /// - **CRITICAL**: Only a payout key can call
+    /// - Caller must be `authorized_payout_key` or a key added with `add_payout_key`
     ///
     /// # State Changes
     /// - Transfers tokens from contract to each recipient
@@ -1237,7 +1326,7 @@ impl ProgramEscrowContract {
     /// ];
     ///
     /// // Execute batch payout (only authorized backend can call)
-    /// let result = escrow_client.batch_payout(&winners, &prizes);
+    /// let result = escrow_client.batch_payout(&program_id, &backend, &winners, &prizes);
     /// println!("Paid {} winners", winners.len());
     /// println!("Remaining: {}", result.remaining_balance);
     /// ```
@@ -1271,6 +1360,7 @@ impl ProgramEscrowContract {
     pub fn batch_payout(
         env: Env,
         program_id: String,
+        caller: Address,
         recipients: Vec<Address>,
         amounts: Vec<i128>,
     ) -> ProgramData {
@@ -1290,11 +1380,15 @@ impl ProgramEscrowContract {
             .get(&program_key)
             .unwrap_or_else(|| panic!("Program not found"));
 
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        // Apply rate limiting to the calling payout key
+        anti_abuse::check_rate_limit(&env, caller.clone());
 
         // Verify authorization - CRITICAL
-        program_data.authorized_payout_key.require_auth();
+        Self::require_payout_key(&env, &program_id, &program_data, &caller);
+
+        if Self::is_expired_internal(&env, &program_data) {
+            panic!("Program expired");
+        }
 
         // Validate inputs
         if recipients.len() != amounts.len() {
@@ -1305,6 +1399,17 @@ impl ProgramEscrowContract {
             panic!("Cannot process empty batch");
         }
 
+        // In strict mode, a recipient may appear at most once per batch
+        if Self::is_strict_batch_internal(&env, &program_id) {
+            let mut seen: Map<Address, ()> = Map::new(&env);
+            for recipient in recipients.iter() {
+                if seen.contains_key(recipient.clone()) {
+                    panic!("Duplicate recipient in batch");
+                }
+                seen.set(recipient, ());
+            }
+        }
+
         // Calculate total with overflow protection
         let mut total_payout: i128 = 0;
         for i in 0..amounts.len() {
@@ -1325,6 +1430,17 @@ impl ProgramEscrowContract {
             );
         }
 
+        // Enforce per-recipient caps across this batch and earlier payouts
+        let mut recipient_paid = Self::get_recipient_paid_internal(&env, &program_id);
+        for i in 0..recipients.len() {
+            Self::add_recipient_paid(
+                &program_data,
+                &mut recipient_paid,
+                &recipients.get(i).unwrap(),
+                amounts.get(i).unwrap(),
+            );
+        }
+
         // Calculate fees if enabled
         let fee_config = Self::get_fee_config_internal(&env);
         let mut total_fees: i128 = 0;
@@ -1361,6 +1477,7 @@ impl ProgramEscrowContract {
                 recipient: recipient.clone(),
                 amount: net_amount,
                 timestamp,
+                schedule_id: None,
             };
             updated_history.push_back(payout_record);
         }
@@ -1385,6 +1502,9 @@ impl ProgramEscrowContract {
 
         // Store updated data
         env.storage().instance().set(&program_key, &updated_data);
+        env.storage()
+            .instance()
+            .set(&DataKey::RecipientPaid(program_id.clone()), &recipient_paid);
 
         // Emit event
         env.events().publish(
@@ -1404,6 +1524,8 @@ impl ProgramEscrowContract {
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `program_id` - The program to pay out from
+    /// * `caller` - One of the program's payout keys
     /// * `recipient` - Address of the prize recipient
     /// * `amount` - Amount to transfer (in token's smallest denomination)
     ///
@@ -1415,9 +1537,11 @@ impl ProgramEscrowContract {
     /// * If program is not initialized
     /// * If amount is zero or negative
     /// * If amount exceeds remaining balance
+    /// * If the program deadline has passed
+    /// * If the recipient's cumulative payouts would exceed `max_per_recipient`
     ///
     /// # Authorization
-    /// - Only authorized payout key can call this function
+    /// - Only a payout key (see `get_payout_keys`) can call this function
     ///
     /// # State Changes
     /// - Transfers tokens from contract to recipient
@@ -1442,7 +1566,7 @@ impl ProgramEscrowContract {
     /// let prize = 1_000_0000000; // $1,000 USDC
     ///
     /// // Execute single payout
-    /// let result = escrow_client.single_payout(&winner, &prize);
+    /// let result = escrow_client.single_payout(&program_id, &backend, &winner, &prize);
     /// println!("Paid {} to winner", prize);
     /// ```
     ///
@@ -1456,6 +1580,7 @@ impl ProgramEscrowContract {
     pub fn single_payout(
         env: Env,
         program_id: String,
+        caller: Address,
         recipient: Address,
         amount: i128,
     ) -> ProgramData {
@@ -1472,15 +1597,13 @@ impl ProgramEscrowContract {
             .get(&program_key)
             .unwrap_or_else(|| panic!("Program not found"));
 
-        program_data.authorized_payout_key.require_auth();
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        Self::require_payout_key(&env, &program_id, &program_data, &caller);
+        // Apply rate limiting to the calling payout key
+        anti_abuse::check_rate_limit(&env, caller.clone());
 
-        // Verify authorization
-        // let caller = env.invoker();
-        // if caller != program_data.authorized_payout_key {
-        //     panic!("Unauthorized: only authorized payout key can trigger payouts");
-        // }
+        if Self::is_expired_internal(&env, &program_data) {
+            panic!("Program expired");
+        }
 
         // Validate amount
         if amount <= 0 {
@@ -1495,6 +1618,10 @@ impl ProgramEscrowContract {
             );
         }
 
+        // Enforce per-recipient cap
+        let mut recipient_paid = Self::get_recipient_paid_internal(&env, &program_id);
+        Self::add_recipient_paid(&program_data, &mut recipient_paid, &recipient, amount);
+
         // Calculate and collect fee if enabled
         let fee_config = Self::get_fee_config_internal(&env);
         let fee_amount = if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
@@ -1530,6 +1657,7 @@ impl ProgramEscrowContract {
             recipient: recipient.clone(),
             amount: net_amount,
             timestamp,
+            schedule_id: None,
         };
 
         let mut updated_history = program_data.payout_history.clone();
@@ -1542,6 +1670,9 @@ impl ProgramEscrowContract {
 
         // Store updated data
         env.storage().instance().set(&program_key, &updated_data);
+        env.storage()
+            .instance()
+            .set(&DataKey::RecipientPaid(program_id.clone()), &recipient_paid);
 
         // Emit Payout event (with net amount after fee)
         // Emit event
@@ -1558,6 +1689,96 @@ impl ProgramEscrowContract {
         updated_data
     }
 
+    /// Withdraws unspent program funds, e.g. back to the funder once the
+    /// program has ended.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to withdraw from
+    /// * `recipient` - Address receiving the funds
+    /// * `amount` - Amount to withdraw (at most `remaining_balance` minus
+    ///   what pending release schedules still need)
+    ///
+    /// # Returns
+    /// * `ProgramData` - Updated program data after the withdrawal
+    ///
+    /// # Panics
+    /// * If contract is paused
+    /// * If program is not initialized
+    /// * If caller is not the authorized payout key
+    /// * If amount is zero or negative
+    /// * If amount exceeds the remaining balance not committed to pending
+    ///   release schedules
+    ///
+    /// # State Changes
+    /// - Transfers tokens from contract to recipient (no fee is charged)
+    /// - Decreases `remaining_balance` and increases `total_withdrawn` by amount
+    /// - Emits Withdraw event
+    ///
+    /// # Events
+    /// Emits: `Withdraw(program_id, recipient, amount, new_balance, total_withdrawn)`
+    pub fn withdraw_remaining(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+    ) -> ProgramData {
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        // Get program data
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        program_data.authorized_payout_key.require_auth();
+        // Apply rate limiting to the authorized payout key
+        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+
+        // Validate amount
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+
+        // Funds committed to pending release schedules can't be withdrawn
+        let available =
+            program_data.remaining_balance - get_program_scheduled_amount(&env, &program_id, None);
+        if amount > available {
+            panic!(
+                "Insufficient balance: requested {}, available {}",
+                amount, available
+            );
+        }
+
+        // Transfer tokens
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+
+        // Update and store program data
+        program_data.remaining_balance -= amount;
+        program_data.total_withdrawn += amount;
+        env.storage().instance().set(&program_key, &program_data);
+
+        // Emit event
+        env.events().publish(
+            (FUNDS_WITHDRAWN,),
+            (
+                program_id,
+                recipient,
+                amount,
+                program_data.remaining_balance,
+                program_data.total_withdrawn,
+            ),
+        );
+
+        program_data
+    }
+
     // ========================================================================
     // Release Schedule Functions
     // ========================================================================
@@ -1580,6 +1801,9 @@ impl ProgramEscrowContract {
     /// * If amount is invalid
     /// * If timestamp is in the past
     /// * If amount exceeds remaining balance
+    /// * If the program has expired, or timestamp is after its deadline
+    /// * If the recipient's payouts plus pending schedules would exceed
+    ///   `max_per_recipient`
     ///
     /// # State Changes
     /// - Creates ProgramReleaseSchedule record
@@ -1638,12 +1862,32 @@ impl ProgramEscrowContract {
             panic!("Release timestamp must be in the future");
         }
 
+        // A schedule has to be releasable before the program expires
+        if Self::is_expired_internal(&env, &program_data) {
+            panic!("Program expired");
+        }
+        if let Some(deadline) = program_data.deadline {
+            if release_timestamp > deadline {
+                panic!("Release timestamp is after the program deadline");
+            }
+        }
+
         // Check sufficient remaining balance
-        let scheduled_total = get_program_total_scheduled_amount(&env, &program_id);
+        let scheduled_total = get_program_scheduled_amount(&env, &program_id, None);
         if scheduled_total + amount > program_data.remaining_balance {
             panic!("Insufficient balance for scheduled amount");
         }
 
+        // The per-recipient cap covers what is already scheduled for them
+        let mut recipient_paid = Self::get_recipient_paid_internal(&env, &program_id);
+        let recipient_scheduled = get_program_scheduled_amount(&env, &program_id, Some(&recipient));
+        Self::add_recipient_paid(
+            &program_data,
+            &mut recipient_paid,
+            &recipient,
+            recipient_scheduled + amount,
+        );
+
         // Get next schedule ID
         let schedule_id: u64 = env
             .storage()
@@ -1717,12 +1961,15 @@ impl ProgramEscrowContract {
     /// * If schedule doesn't exist
     /// * If schedule is already released
     /// * If schedule is not yet due
+    /// * If the program has expired
+    /// * If the payout would exceed the recipient's `max_per_recipient`
     ///
     /// # State Changes
     /// - Transfers tokens to recipient
     /// - Updates schedule status to released
     /// - Adds to release history
     /// - Updates program remaining balance
+    /// - Appends a schedule-marked record to the payout history
     /// - Emits ScheduleReleased event
     ///
     /// # Example
@@ -1773,12 +2020,26 @@ impl ProgramEscrowContract {
             panic!("Schedule not yet due for release");
         }
 
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
+        if Self::is_expired_internal(&env, &program_data) {
+            panic!("Program expired");
+        }
+
+        // Enforce per-recipient cap
+        let mut recipient_paid = Self::get_recipient_paid_internal(&env, &program_id);
+        Self::add_recipient_paid(
+            &program_data,
+            &mut recipient_paid,
+            &schedule.recipient,
+            schedule.amount,
+        );
 
         // Transfer funds
         #[cfg(not(test))]
-        token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+        {
+            let contract_address = env.current_contract_address();
+            let token_client = token::Client::new(&env, &program_data.token_address);
+            token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+        }
 
         // Update schedule
         schedule.released = true;
@@ -1789,6 +2050,12 @@ impl ProgramEscrowContract {
         // Update program data
         let mut updated_data = program_data.clone();
         updated_data.remaining_balance -= schedule.amount;
+        updated_data.payout_history.push_back(PayoutRecord {
+            recipient: schedule.recipient.clone(),
+            amount: schedule.amount,
+            timestamp: now,
+            schedule_id: Some(schedule_id),
+        });
 
         // Add to release history
         let history_entry = ProgramReleaseHistory {
@@ -1817,6 +2084,9 @@ impl ProgramEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
+        env.storage()
+            .instance()
+            .set(&DataKey::RecipientPaid(program_id.clone()), &recipient_paid);
 
         // Emit program schedule released event
         env.events().publish(
@@ -1853,12 +2123,15 @@ impl ProgramEscrowContract {
     /// * If caller is not authorized payout key
     /// * If schedule doesn't exist
     /// * If schedule is already released
+    /// * If the program has expired
+    /// * If the payout would exceed the recipient's `max_per_recipient`
     ///
     /// # State Changes
     /// - Transfers tokens to recipient
     /// - Updates schedule status to released
     /// - Adds to release history
     /// - Updates program remaining balance
+    /// - Appends a schedule-marked record to the payout history
     /// - Emits ScheduleReleased event
     ///
     /// # Authorization
@@ -1906,12 +2179,26 @@ impl ProgramEscrowContract {
             panic!("Schedule already released");
         }
 
-        // Get token client
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
+        if Self::is_expired_internal(&env, &program_data) {
+            panic!("Program expired");
+        }
+
+        // Enforce per-recipient cap
+        let mut recipient_paid = Self::get_recipient_paid_internal(&env, &program_id);
+        Self::add_recipient_paid(
+            &program_data,
+            &mut recipient_paid,
+            &schedule.recipient,
+            schedule.amount,
+        );
+
         // Transfer funds
         #[cfg(not(test))]
-        token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+        {
+            let contract_address = env.current_contract_address();
+            let token_client = token::Client::new(&env, &program_data.token_address);
+            token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+        }
 
         // Update schedule
         let now = env.ledger().timestamp();
@@ -1922,6 +2209,12 @@ impl ProgramEscrowContract {
         // Update program data
         let mut updated_data = program_data.clone();
         updated_data.remaining_balance -= schedule.amount;
+        updated_data.payout_history.push_back(PayoutRecord {
+            recipient: schedule.recipient.clone(),
+            amount: schedule.amount,
+            timestamp: now,
+            schedule_id: Some(schedule_id),
+        });
 
         // Add to release history
         let history_entry = ProgramReleaseHistory {
@@ -1950,6 +2243,9 @@ impl ProgramEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
+        env.storage()
+            .instance()
+            .set(&DataKey::RecipientPaid(program_id.clone()), &recipient_paid);
 
         // Emit program schedule released event
         env.events().publish(
@@ -2045,93 +2341,312 @@ impl ProgramEscrowContract {
         program_data.remaining_balance
     }
 
-    /// Update fee configuration (admin only - uses authorized_payout_key)
+    /// Sets the program's payout deadline.
+    ///
+    /// Once `env.ledger().timestamp()` passes the deadline, `single_payout`,
+    /// `batch_payout` and release schedules panic with "Program expired".
+    /// Unspent funds can still be recovered with `withdraw_remaining`.
     ///
     /// # Arguments
-    /// * `lock_fee_rate` - Optional new lock fee rate (basis points)
-    /// * `payout_fee_rate` - Optional new payout fee rate (basis points)
-    /// * `fee_recipient` - Optional new fee recipient address
-    /// * `fee_enabled` - Optional fee enable/disable flag
-    pub fn update_fee_config(
-        env: Env,
-        lock_fee_rate: Option<i128>,
-        payout_fee_rate: Option<i128>,
-        fee_recipient: Option<Address>,
-        fee_enabled: Option<bool>,
-    ) {
-        // Verify authorization
-        let program_data: ProgramData = env
+    /// * `program_id` - The program to update
+    /// * `deadline` - Unix timestamp of the last second payouts are allowed
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    /// * If caller is not the authorized payout key
+    /// * If a deadline is already set (it can't be extended)
+    /// * If deadline is in the past
+    pub fn set_program_deadline(env: Env, program_id: String, deadline: u64) -> ProgramData {
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data: ProgramData = env
             .storage()
             .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| panic!("Program not initialized"));
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
 
-        // Note: In Soroban, we check authorization by requiring auth from the authorized key
-        // For this function, we'll require auth from the authorized_payout_key
         program_data.authorized_payout_key.require_auth();
 
-        let mut fee_config = Self::get_fee_config_internal(&env);
-
-        if let Some(rate) = lock_fee_rate {
-            if !(0..=MAX_FEE_RATE).contains(&rate) {
-                panic!(
-                    "Invalid lock fee rate: must be between 0 and {}",
-                    MAX_FEE_RATE
-                );
-            }
-            fee_config.lock_fee_rate = rate;
-        }
-
-        if let Some(rate) = payout_fee_rate {
-            if !(0..=MAX_FEE_RATE).contains(&rate) {
-                panic!(
-                    "Invalid payout fee rate: must be between 0 and {}",
-                    MAX_FEE_RATE
-                );
-            }
-            fee_config.payout_fee_rate = rate;
-        }
-
-        if let Some(recipient) = fee_recipient {
-            fee_config.fee_recipient = recipient;
+        if program_data.deadline.is_some() {
+            panic!("Deadline already set");
         }
-
-        if let Some(enabled) = fee_enabled {
-            fee_config.fee_enabled = enabled;
+        if deadline < env.ledger().timestamp() {
+            panic!("Deadline must be in the future");
         }
 
-        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+        program_data.deadline = Some(deadline);
+        env.storage().instance().set(&program_key, &program_data);
 
-        // Emit fee config updated event
         env.events().publish(
-            (symbol_short!("fee_cfg"),),
-            (
-                fee_config.lock_fee_rate,
-                fee_config.payout_fee_rate,
-                fee_config.fee_recipient,
-                fee_config.fee_enabled,
-            ),
+            (symbol_short!("deadline"),),
+            (program_id, deadline),
         );
-    }
 
-    /// Get current fee configuration (view function)
-    pub fn get_fee_config(env: Env) -> FeeConfig {
-        Self::get_fee_config_internal(&env)
+        program_data
     }
 
-    /// Gets the total number of programs registered.
+    /// Sets the maximum cumulative amount any single recipient can be paid
+    /// through `single_payout` / `batch_payout` and release schedules.
     ///
-    /// # Returns
-    /// * `u32` - Count of registered programs
-    pub fn get_program_count(env: Env) -> u32 {
-        let registry: Vec<String> = env
+    /// Amounts are counted before fees. `None` removes the cap.
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    /// * If caller is not the authorized payout key
+    /// * If cap is zero or negative
+    pub fn set_recipient_cap(env: Env, program_id: String, cap: Option<i128>) -> ProgramData {
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data: ProgramData = env
             .storage()
             .instance()
-            .get(&PROGRAM_REGISTRY)
-            .unwrap_or(vec![&env]);
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
 
-        registry.len()
-    }
+        program_data.authorized_payout_key.require_auth();
+
+        if let Some(cap) = cap {
+            if cap <= 0 {
+                panic!("Cap must be greater than zero");
+            }
+        }
+
+        program_data.max_per_recipient = cap;
+        env.storage().instance().set(&program_key, &program_data);
+
+        env.events().publish(
+            (symbol_short!("rcpt_cap"),),
+            (program_id, cap),
+        );
+
+        program_data
+    }
+
+    /// Adds a key that can trigger `single_payout` and `batch_payout`.
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    /// * If caller is not already a payout key
+    /// * If key is already a payout key
+    pub fn add_payout_key(
+        env: Env,
+        program_id: String,
+        caller: Address,
+        key: Address,
+    ) -> Vec<Address> {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::require_payout_key(&env, &program_id, &program_data, &caller);
+
+        let mut keys = Self::get_payout_keys_internal(&env, &program_id, &program_data);
+        if keys.contains(&key) {
+            panic!("Payout key already exists");
+        }
+        keys.push_back(key.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::PayoutKeys(program_id.clone()), &keys);
+
+        env.events().publish(
+            (symbol_short!("key_add"),),
+            (program_id, key, caller),
+        );
+
+        keys
+    }
+
+    /// Removes a payout key. The original `authorized_payout_key` can't be
+    /// removed.
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    /// * If caller is not a payout key
+    /// * If key is the original authorized payout key
+    /// * If key is not a payout key
+    pub fn remove_payout_key(
+        env: Env,
+        program_id: String,
+        caller: Address,
+        key: Address,
+    ) -> Vec<Address> {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::require_payout_key(&env, &program_id, &program_data, &caller);
+
+        if key == program_data.authorized_payout_key {
+            panic!("Cannot remove the authorized payout key");
+        }
+
+        let mut keys = Self::get_payout_keys_internal(&env, &program_id, &program_data);
+        let index = keys
+            .first_index_of(&key)
+            .unwrap_or_else(|| panic!("Payout key not found"));
+        keys.remove(index);
+        env.storage()
+            .instance()
+            .set(&DataKey::PayoutKeys(program_id.clone()), &keys);
+
+        env.events().publish(
+            (symbol_short!("key_rm"),),
+            (program_id, key, caller),
+        );
+
+        keys
+    }
+
+    /// Returns the keys allowed to trigger payouts, starting with the
+    /// original `authorized_payout_key`.
+    pub fn get_payout_keys(env: Env, program_id: String) -> Vec<Address> {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::get_payout_keys_internal(&env, &program_id, &program_data)
+    }
+
+    /// Enables or disables strict batch mode, in which `batch_payout` panics
+    /// with "Duplicate recipient in batch" if a recipient is listed twice.
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    /// * If caller is not the authorized payout key
+    pub fn set_strict_batch(env: Env, program_id: String, enabled: bool) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        program_data.authorized_payout_key.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::StrictBatch(program_id), &enabled);
+    }
+
+    /// Returns true if `batch_payout` rejects duplicate recipients.
+    pub fn is_strict_batch(env: Env, program_id: String) -> bool {
+        Self::is_strict_batch_internal(&env, &program_id)
+    }
+
+    /// Returns the cumulative amount (before fees) paid to `recipient` by
+    /// this program's payouts.
+    pub fn amount_paid_to(env: Env, program_id: String, recipient: Address) -> i128 {
+        Self::get_recipient_paid_internal(&env, &program_id)
+            .get(recipient)
+            .unwrap_or(0)
+    }
+
+    /// Returns true once the program's deadline has passed.
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    pub fn is_expired(env: Env, program_id: String) -> bool {
+        let program_key = DataKey::Program(program_id);
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::is_expired_internal(&env, &program_data)
+    }
+
+    /// Update fee configuration (admin only - uses authorized_payout_key)
+    ///
+    /// # Arguments
+    /// * `lock_fee_rate` - Optional new lock fee rate (basis points)
+    /// * `payout_fee_rate` - Optional new payout fee rate (basis points)
+    /// * `fee_recipient` - Optional new fee recipient address
+    /// * `fee_enabled` - Optional fee enable/disable flag
+    pub fn update_fee_config(
+        env: Env,
+        lock_fee_rate: Option<i128>,
+        payout_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_enabled: Option<bool>,
+    ) {
+        // Verify authorization
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+
+        // Note: In Soroban, we check authorization by requiring auth from the authorized key
+        // For this function, we'll require auth from the authorized_payout_key
+        program_data.authorized_payout_key.require_auth();
+
+        let mut fee_config = Self::get_fee_config_internal(&env);
+
+        if let Some(rate) = lock_fee_rate {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                panic!(
+                    "Invalid lock fee rate: must be between 0 and {}",
+                    MAX_FEE_RATE
+                );
+            }
+            fee_config.lock_fee_rate = rate;
+        }
+
+        if let Some(rate) = payout_fee_rate {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                panic!(
+                    "Invalid payout fee rate: must be between 0 and {}",
+                    MAX_FEE_RATE
+                );
+            }
+            fee_config.payout_fee_rate = rate;
+        }
+
+        if let Some(recipient) = fee_recipient {
+            fee_config.fee_recipient = recipient;
+        }
+
+        if let Some(enabled) = fee_enabled {
+            fee_config.fee_enabled = enabled;
+        }
+
+        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+
+        // Emit fee config updated event
+        env.events().publish(
+            (symbol_short!("fee_cfg"),),
+            (
+                fee_config.lock_fee_rate,
+                fee_config.payout_fee_rate,
+                fee_config.fee_recipient,
+                fee_config.fee_enabled,
+            ),
+        );
+    }
+
+    /// Get current fee configuration (view function)
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        Self::get_fee_config_internal(&env)
+    }
+
+    /// Gets the total number of programs registered.
+    ///
+    /// # Returns
+    /// * `u32` - Count of registered programs
+    pub fn get_program_count(env: Env) -> u32 {
+        let registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env]);
+
+        registry.len()
+    }
 
     // ========================================================================
     // Query Functions
@@ -2206,7 +2721,8 @@ impl ProgramEscrowContract {
     pub fn get_payouts(
         env: Env, 
         program_id: String,
-        filter: PayoutFilter
+        filter: PayoutFilter,
+        pagination: Pagination
     ) -> Vec<PayoutRecord> {
         let program_key = DataKey::Program(program_id);
         if !env.storage().instance().has(&program_key) {
@@ -2220,8 +2736,15 @@ impl ProgramEscrowContract {
             .unwrap();
             
         let mut result = vec![&env];
+        let mut count: u32 = 0;
+        let mut skipped: u64 = 0;
         
         for record in program.payout_history.iter() {
+            // Check pagination limit
+            if count >= pagination.limit {
+                break;
+            }
+            
             // Recipient filter
             if let Some(recipient) = &filter.recipient {
                 if &record.recipient != recipient {
@@ -2253,12 +2776,40 @@ impl ProgramEscrowContract {
                 }
             }
             
+            // Apply Pagination Skip
+            if skipped < pagination.start_index {
+                skipped += 1;
+                continue;
+            }
+            
             result.push_back(record);
+            count += 1;
         }
         
         result
     }
 
+    /// Number of payouts recorded for a program.
+    ///
+    /// # Panics
+    /// * If program is not found
+    pub fn get_payout_count(env: Env, program_id: String) -> u32 {
+        Self::get_program_info(env, program_id).payout_history.len()
+    }
+
+    /// Retrieves a single payout record by its position in the program's
+    /// payout history (0 is the oldest).
+    ///
+    /// # Panics
+    /// * If program is not found
+    /// * If `index` is not below `get_payout_count`
+    pub fn get_payout(env: Env, program_id: String, index: u32) -> PayoutRecord {
+        Self::get_program_info(env, program_id)
+            .payout_history
+            .get(index)
+            .unwrap_or_else(|| panic!("Payout index out of range"))
+    }
+
     /// Get aggregate statistics for all programs.
     ///
     /// # Performance
@@ -2504,8 +3055,13 @@ impl ProgramEscrowContract {
     }
 }
 
-/// Helper function to calculate total scheduled amount for a program.
-fn get_program_total_scheduled_amount(env: &Env, program_id: &String) -> i128 {
+/// Helper function to calculate the pending scheduled amount for a program,
+/// or only the part scheduled for `recipient`.
+fn get_program_scheduled_amount(
+    env: &Env,
+    program_id: &String,
+    recipient: Option<&Address>,
+) -> i128 {
     let next_id: u64 = env
         .storage()
         .persistent()
@@ -2524,7 +3080,7 @@ fn get_program_total_scheduled_amount(env: &Env, program_id: &String) -> i128 {
                 .persistent()
                 .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
                 .unwrap();
-            if !schedule.released {
+            if !schedule.released && recipient.is_none_or(|r| *r == schedule.recipient) {
                 total += schedule.amount;
             }
         }
@@ -2533,11 +3089,12 @@ fn get_program_total_scheduled_amount(env: &Env, program_id: &String) -> i128 {
     total
 }
 
-/// ============================================================================
+// ============================================================================
 // Tests
 // ============================================================================
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping)]
 mod test {
     use super::*;
     use soroban_sdk::{
@@ -2552,10 +3109,17 @@ mod test {
         token::Client::new(env, &token_address)
     }
 
+    fn create_funded_account(env: &Env, token: &Address, amount: &i128) -> Address {
+        let account = Address::generate(env);
+        token::StellarAssetClient::new(env, token).mint(&account, amount);
+        account
+    }
+
     // ========================================================================
     // Program Registration Tests
     // ========================================================================
 
+    #[allow(clippy::too_many_arguments)]
     fn setup_program_with_schedule(
         env: &Env,
         client: &ProgramEscrowContractClient<'static>,
@@ -2569,26 +3133,19 @@ mod test {
         // Register program
         client.initialize_program(program_id, authorized_key, token);
 
-        // Create and fund token
-        let token_client = token::Client::new(env, token);
+        // Fund the authorized key
         let token_admin = token::StellarAssetClient::new(env, token);
         token_admin.mint(authorized_key, &total_amount);
 
         // Lock funds for program
-        token_client.approve(
-            authorized_key,
-            &client.address,
-            &total_amount,
-            &1000,
-        );
-        client.lock_program_funds(program_id, &total_amount);
+        client.lock_program_funds(program_id, authorized_key, &total_amount);
 
         // Create release schedule
         client.create_program_release_schedule(
             program_id,
             &total_amount,
             &release_timestamp,
-            &winner,
+            winner,
         );
     }
 
@@ -2656,19 +3213,12 @@ mod test {
         // Register program
         client.initialize_program(&program_id, &authorized_key, &token);
 
-        // Create and fund token
-        let token_client = create_token_contract(&env, &authorized_key);
-        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+        // Fund the authorized key
+        let token_admin = token::StellarAssetClient::new(&env, &token);
         token_admin.mint(&authorized_key, &total_amount);
 
         // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &client.address,
-            &total_amount,
-            &1000,
-        );
-        client.lock_program_funds(&program_id, &total_amount);
+        client.lock_program_funds(&program_id, &authorized_key, &total_amount);
 
         // Create first release schedule
         client.create_program_release_schedule(&program_id, &amount1, &1000, &winner1.clone());
@@ -2758,6 +3308,46 @@ mod test {
         // Event verification can be added later - focusing on core functionality
     }
 
+    #[test]
+    fn test_program_schedule_release_records_payout() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let authorized_key = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let token_client = create_token_contract(&env, &authorized_key);
+        let token = token_client.address.clone();
+        let program_id = String::from_str(&env, "Hackathon2024");
+        let amount = 1000_0000000;
+
+        env.mock_all_auths();
+
+        setup_program_with_schedule(
+            &env,
+            &client,
+            &authorized_key,
+            &token,
+            &program_id,
+            amount,
+            &winner,
+            1000,
+        );
+        assert_eq!(client.get_payout_count(&program_id), 0);
+
+        env.ledger().set_timestamp(1001);
+        client.release_prog_schedule_automatic(&program_id, &1);
+
+        let info = client.get_program_info(&program_id);
+        assert_eq!(info.remaining_balance, 0);
+        assert_eq!(info.payout_history.len(), 1);
+        let payout = info.payout_history.get(0).unwrap();
+        assert_eq!(payout.recipient, winner);
+        assert_eq!(payout.amount, amount);
+        assert_eq!(payout.timestamp, 1001);
+        assert_eq!(payout.schedule_id, Some(1));
+    }
+
     #[test]
     fn test_program_manual_trigger_before_after_timestamp() {
         let env = Env::default();
@@ -2805,39 +3395,126 @@ mod test {
     }
 
     #[test]
-    fn test_verify_program_schedule_tracking_and_history() {
+    fn test_program_schedule_respects_recipient_cap() {
         let env = Env::default();
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
         let authorized_key = Address::generate(&env);
-        let winner1 = Address::generate(&env);
-        let winner2 = Address::generate(&env);
+        let winner = Address::generate(&env);
         let token_client = create_token_contract(&env, &authorized_key);
         let token = token_client.address.clone();
         let program_id = String::from_str(&env, "Hackathon2024");
-        let amount1 = 600_0000000;
-        let amount2 = 400_0000000;
-        let total_amount = amount1 + amount2;
 
         env.mock_all_auths();
 
-        // Register program
         client.initialize_program(&program_id, &authorized_key, &token);
+        let token_admin = token::StellarAssetClient::new(&env, &token);
+        token_admin.mint(&authorized_key, &10_000_0000000);
+        client.lock_program_funds(&program_id, &authorized_key, &10_000_0000000);
+        client.set_recipient_cap(&program_id, &Some(3_000_0000000));
 
-        // Create and fund token
-        let token_client = create_token_contract(&env, &authorized_key);
-        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
-        token_admin.mint(&authorized_key, &total_amount);
+        client.single_payout(&program_id, &authorized_key, &winner, &1_000_0000000);
+        client.create_program_release_schedule(&program_id, &1_500_0000000, &1000, &winner);
 
-        // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &client.address,
-            &total_amount,
+        // Paid plus already scheduled leaves only 500 under the cap
+        let result = client.try_create_program_release_schedule(
+            &program_id,
+            &1_000_0000000,
             &1000,
+            &winner,
+        );
+        assert!(result.is_err());
+
+        // A cap lowered after scheduling is enforced at release
+        client.set_recipient_cap(&program_id, &Some(2_000_0000000));
+        env.ledger().set_timestamp(1001);
+        assert!(client
+            .try_release_prog_schedule_automatic(&program_id, &1)
+            .is_err());
+        assert_eq!(client.amount_paid_to(&program_id, &winner), 1_000_0000000);
+
+        client.set_recipient_cap(&program_id, &Some(3_000_0000000));
+        client.release_prog_schedule_automatic(&program_id, &1);
+        assert_eq!(client.amount_paid_to(&program_id, &winner), 2_500_0000000);
+    }
+
+    #[test]
+    fn test_program_schedule_respects_deadline() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let authorized_key = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let token_client = create_token_contract(&env, &authorized_key);
+        let token = token_client.address.clone();
+        let program_id = String::from_str(&env, "Hackathon2024");
+
+        env.mock_all_auths();
+
+        client.initialize_program(&program_id, &authorized_key, &token);
+        let token_admin = token::StellarAssetClient::new(&env, &token);
+        token_admin.mint(&authorized_key, &10_000_0000000);
+        client.lock_program_funds(&program_id, &authorized_key, &10_000_0000000);
+        client.set_program_deadline(&program_id, &2000);
+
+        // A schedule due after the deadline could never be released
+        let result = client.try_create_program_release_schedule(
+            &program_id,
+            &1_000_0000000,
+            &2500,
+            &winner,
         );
-        client.lock_program_funds(&program_id, &total_amount);
+        assert!(result.is_err());
+
+        client.create_program_release_schedule(&program_id, &1_000_0000000, &1500, &winner);
+        client.create_program_release_schedule(&program_id, &1_000_0000000, &2000, &winner);
+
+        // Neither path releases once the program has expired
+        env.ledger().set_timestamp(2001);
+        assert!(client
+            .try_release_prog_schedule_automatic(&program_id, &1)
+            .is_err());
+        assert!(client
+            .try_release_program_schedule_manual(&program_id, &2)
+            .is_err());
+        assert!(client
+            .try_create_program_release_schedule(&program_id, &1_000_0000000, &3000, &winner)
+            .is_err());
+        assert_eq!(
+            client.get_program_info(&program_id).remaining_balance,
+            10_000_0000000
+        );
+    }
+
+    #[test]
+    fn test_verify_program_schedule_tracking_and_history() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let authorized_key = Address::generate(&env);
+        let winner1 = Address::generate(&env);
+        let winner2 = Address::generate(&env);
+        let token_client = create_token_contract(&env, &authorized_key);
+        let token = token_client.address.clone();
+        let program_id = String::from_str(&env, "Hackathon2024");
+        let amount1 = 600_0000000;
+        let amount2 = 400_0000000;
+        let total_amount = amount1 + amount2;
+
+        env.mock_all_auths();
+
+        // Register program
+        client.initialize_program(&program_id, &authorized_key, &token);
+
+        // Fund the authorized key
+        let token_admin = token::StellarAssetClient::new(&env, &token);
+        token_admin.mint(&authorized_key, &total_amount);
+
+        // Lock funds for program
+        client.lock_program_funds(&program_id, &authorized_key, &total_amount);
 
         // Create first schedule
         client.create_program_release_schedule(&program_id, &amount1, &1000, &winner1.clone());
@@ -2905,19 +3582,12 @@ mod test {
         // Register program
         client.initialize_program(&program_id, &authorized_key, &token);
 
-        // Create and fund token
-        let token_client = create_token_contract(&env, &authorized_key);
-        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+        // Fund the authorized key
+        let token_admin = token::StellarAssetClient::new(&env, &token);
         token_admin.mint(&authorized_key, &total_amount);
 
         // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &client.address,
-            &total_amount,
-            &1000,
-        );
-        client.lock_program_funds(&program_id, &total_amount);
+        client.lock_program_funds(&program_id, &authorized_key, &total_amount);
 
         // Create overlapping schedules (all at same timestamp)
         client.create_program_release_schedule(
@@ -2995,6 +3665,24 @@ mod test {
         assert_eq!(client.get_program_count(), 1);
     }
 
+    #[test]
+    fn test_performance_stats_record_last_called() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        env.ledger().set_timestamp(12_345);
+        client.initialize_program(&prog_id, &backend, &token);
+
+        let stats = client.get_performance_stats(&symbol_short!("init_prg"));
+        assert_eq!(stats.call_count, 1);
+        assert_eq!(stats.last_called, 12_345);
+    }
+
     #[test]
     fn test_multiple_programs_isolation() {
         let env = Env::default();
@@ -3104,12 +3792,42 @@ mod test {
 
         // Lock funds
         let amount = 10_000_0000000i128; // 10,000 USDC
-        let updated = client.lock_program_funds(&prog_id, &amount);
+        let organizer = create_funded_account(&env, &token_client.address, &amount);
+        let updated = client.lock_program_funds(&prog_id, &organizer, &amount);
 
         assert_eq!(updated.total_funds, amount);
         assert_eq!(updated.remaining_balance, amount);
     }
 
+    #[test]
+    fn test_lock_funds_transfers_from_depositor() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+
+        let from = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        let updated = client.lock_program_funds(&prog_id, &from, &4_000_0000000);
+
+        assert_eq!(
+            env.auths()[0].0,
+            from,
+            "lock_program_funds must be authorized by the depositor"
+        );
+        assert_eq!(token_client.balance(&from), 6_000_0000000);
+        assert_eq!(token_client.balance(&contract_id), 4_000_0000000);
+        assert_eq!(updated.total_funds, 4_000_0000000);
+        assert_eq!(updated.remaining_balance, 4_000_0000000);
+        assert_eq!(client.get_remaining_balance(&prog_id), 4_000_0000000);
+    }
+
     #[test]
     fn test_lock_funds_multiple_programs_isolation() {
         let env = Env::default();
@@ -3133,9 +3851,10 @@ mod test {
         // Lock different amounts in each program
         let amount1 = 5_000_0000000i128;
         let amount2 = 10_000_0000000i128;
+        let organizer = create_funded_account(&env, &token_client.address, &(amount1 + amount2));
 
-        client.lock_program_funds(&prog1, &amount1);
-        client.lock_program_funds(&prog2, &amount2);
+        client.lock_program_funds(&prog1, &organizer, &amount1);
+        client.lock_program_funds(&prog2, &organizer, &amount2);
 
         // Verify isolation - funds don't mix
         let info1 = client.get_program_info(&prog1);
@@ -3163,9 +3882,10 @@ mod test {
         client.initialize_program(&prog_id, &backend, &token_client.address);
 
         // Lock funds multiple times
-        client.lock_program_funds(&prog_id, &1_000_0000000);
-        client.lock_program_funds(&prog_id, &2_000_0000000);
-        client.lock_program_funds(&prog_id, &3_000_0000000);
+        let organizer = create_funded_account(&env, &token_client.address, &6_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &1_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &2_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &3_000_0000000);
 
         let info = client.get_program_info(&prog_id);
         assert_eq!(info.total_funds, 6_000_0000000);
@@ -3176,6 +3896,7 @@ mod test {
     #[should_panic(expected = "Amount must be greater than zero")]
     fn test_lock_zero_funds() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
@@ -3184,7 +3905,7 @@ mod test {
         let prog_id = String::from_str(&env, "Hackathon2024");
 
         client.initialize_program(&prog_id, &backend, &token);
-        client.lock_program_funds(&prog_id, &0);
+        client.lock_program_funds(&prog_id, &Address::generate(&env), &0);
     }
 
     // ========================================================================
@@ -3206,12 +3927,13 @@ mod test {
         let prog_id = String::from_str(&env, "Test");
 
         client.initialize_program(&prog_id, &backend, &token_client.address);
-        client.lock_program_funds(&prog_id, &10_000_0000000);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
 
         let recipients = soroban_sdk::vec![&env, Address::generate(&env), Address::generate(&env)];
         let amounts = soroban_sdk::vec![&env, 1_000_0000000i128]; // Mismatch!
 
-        client.batch_payout(&prog_id, &recipients, &amounts);
+        client.batch_payout(&prog_id, &backend, &recipients, &amounts);
     }
 
     #[test]
@@ -3229,12 +3951,436 @@ mod test {
         let prog_id = String::from_str(&env, "Test");
 
         client.initialize_program(&prog_id, &backend, &token_client.address);
-        client.lock_program_funds(&prog_id, &5_000_0000000);
+        let organizer = create_funded_account(&env, &token_client.address, &5_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &5_000_0000000);
 
         let recipients = soroban_sdk::vec![&env, Address::generate(&env)];
         let amounts = soroban_sdk::vec![&env, 10_000_0000000i128]; // More than available!
 
-        client.batch_payout(&prog_id, &recipients, &amounts);
+        client.batch_payout(&prog_id, &backend, &recipients, &amounts);
+    }
+
+    // ========================================================================
+    // Withdrawal Tests
+    // ========================================================================
+
+    #[test]
+    fn test_withdraw_remaining_after_payout() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let funder = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
+
+        client.single_payout(&prog_id, &backend, &winner, &4_000_0000000);
+        let updated = client.withdraw_remaining(&prog_id, &funder, &6_000_0000000);
+
+        assert_eq!(updated.remaining_balance, 0);
+        assert_eq!(client.get_remaining_balance(&prog_id), 0);
+        assert_eq!(token_client.balance(&funder), 6_000_0000000);
+        assert_eq!(token_client.balance(&contract_id), 0);
+        // Withdrawals are not payouts
+        assert_eq!(updated.payout_history.len(), 1);
+    }
+
+    #[test]
+    fn test_withdrawals_keep_funds_invariant() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let funder = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
+        client.single_payout(&prog_id, &backend, &winner, &3_000_0000000);
+
+        env.ledger().with_mut(|li| li.timestamp += 120);
+        client.withdraw_remaining(&prog_id, &funder, &2_000_0000000);
+        env.ledger().with_mut(|li| li.timestamp += 120);
+        let data = client.withdraw_remaining(&prog_id, &funder, &1_000_0000000);
+        assert_eq!(data.total_withdrawn, 3_000_0000000);
+
+        let paid: i128 = data.payout_history.iter().map(|p| p.amount).sum();
+        assert_eq!(
+            data.total_funds,
+            data.remaining_balance + paid + data.total_withdrawn
+        );
+        assert_eq!(token_client.balance(&funder), data.total_withdrawn);
+    }
+
+    #[test]
+    #[should_panic(expected = "Insufficient balance")]
+    fn test_withdraw_remaining_exceeds_balance() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &5_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &5_000_0000000);
+
+        client.withdraw_remaining(&prog_id, &Address::generate(&env), &5_000_0000001);
+    }
+
+    #[test]
+    #[should_panic(expected = "Insufficient balance")]
+    fn test_withdraw_remaining_keeps_scheduled_funds() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let funder = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &5_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &5_000_0000000);
+        client.create_program_release_schedule(&prog_id, &3_000_0000000, &1000, &winner);
+
+        // Only the unscheduled part can be withdrawn
+        client.withdraw_remaining(&prog_id, &funder, &2_000_0000000);
+        assert_eq!(token_client.balance(&funder), 2_000_0000000);
+        client.withdraw_remaining(&prog_id, &funder, &1);
+    }
+
+    // ========================================================================
+    // Deadline Tests
+    // ========================================================================
+
+    #[test]
+    fn test_payout_at_deadline() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
+        client.set_program_deadline(&prog_id, &1000);
+
+        env.ledger().set_timestamp(1000);
+        assert!(!client.is_expired(&prog_id));
+        client.single_payout(&prog_id, &backend, &winner, &1_000_0000000);
+        assert_eq!(token_client.balance(&winner), 1_000_0000000);
+
+        env.ledger().set_timestamp(1001);
+        assert!(client.is_expired(&prog_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "Program expired")]
+    fn test_single_payout_after_deadline() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
+        client.set_program_deadline(&prog_id, &1000);
+
+        env.ledger().set_timestamp(1001);
+        client.single_payout(&prog_id, &backend, &Address::generate(&env), &1_000_0000000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Program expired")]
+    fn test_batch_payout_after_deadline() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
+        client.set_program_deadline(&prog_id, &1000);
+
+        env.ledger().set_timestamp(1001);
+        let recipients = soroban_sdk::vec![&env, Address::generate(&env)];
+        let amounts = soroban_sdk::vec![&env, 1_000_0000000i128];
+        client.batch_payout(&prog_id, &backend, &recipients, &amounts);
+    }
+
+    #[test]
+    #[should_panic(expected = "Deadline already set")]
+    fn test_program_deadline_cannot_be_extended() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token);
+        client.set_program_deadline(&prog_id, &1000);
+        client.set_program_deadline(&prog_id, &2000);
+    }
+
+    // ========================================================================
+    // Recipient Cap Tests
+    // ========================================================================
+
+    #[test]
+    fn test_recipient_cap_tracks_cumulative_payouts() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let other = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
+        client.set_recipient_cap(&prog_id, &Some(3_000_0000000));
+
+        client.single_payout(&prog_id, &backend, &winner, &1_000_0000000);
+        let recipients = soroban_sdk::vec![&env, winner.clone(), other.clone()];
+        let amounts = soroban_sdk::vec![&env, 2_000_0000000i128, 500_0000000i128];
+        client.batch_payout(&prog_id, &backend, &recipients, &amounts);
+
+        assert_eq!(client.amount_paid_to(&prog_id, &winner), 3_000_0000000);
+        assert_eq!(client.amount_paid_to(&prog_id, &other), 500_0000000);
+
+        // Winner is at the cap, so even the smallest payout is rejected
+        let result = client.try_single_payout(&prog_id, &backend, &winner, &1);
+        assert!(result.is_err());
+        assert_eq!(client.amount_paid_to(&prog_id, &winner), 3_000_0000000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Recipient cap exceeded")]
+    fn test_recipient_cap_exceeded_within_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
+        client.set_recipient_cap(&prog_id, &Some(3_000_0000000));
+
+        let recipients = soroban_sdk::vec![&env, winner.clone(), winner.clone()];
+        let amounts = soroban_sdk::vec![&env, 2_000_0000000i128, 2_000_0000000i128];
+        client.batch_payout(&prog_id, &backend, &recipients, &amounts);
+    }
+
+    #[test]
+    #[should_panic(expected = "Duplicate recipient in batch")]
+    fn test_strict_batch_rejects_duplicate_recipient() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
+        client.set_strict_batch(&prog_id, &true);
+
+        let recipients = soroban_sdk::vec![
+            &env,
+            winner.clone(),
+            Address::generate(&env),
+            winner.clone()
+        ];
+        let amounts = soroban_sdk::vec![&env, 1_000_0000000i128, 1_000_0000000, 1_000_0000000];
+        client.batch_payout(&prog_id, &backend, &recipients, &amounts);
+    }
+
+    #[test]
+    fn test_strict_batch_allows_unique_recipients() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
+        assert!(!client.is_strict_batch(&prog_id));
+        client.set_strict_batch(&prog_id, &true);
+        assert!(client.is_strict_batch(&prog_id));
+
+        let winner1 = Address::generate(&env);
+        let winner2 = Address::generate(&env);
+        let recipients = soroban_sdk::vec![&env, winner1.clone(), winner2.clone()];
+        let amounts = soroban_sdk::vec![&env, 1_000_0000000i128, 2_000_0000000];
+        let updated = client.batch_payout(&prog_id, &backend, &recipients, &amounts);
+
+        assert_eq!(updated.remaining_balance, 7_000_0000000);
+        assert_eq!(token_client.balance(&winner1), 1_000_0000000);
+        assert_eq!(token_client.balance(&winner2), 2_000_0000000);
+    }
+
+    // ========================================================================
+    // Payout Key Tests
+    // ========================================================================
+
+    #[test]
+    fn test_multiple_payout_keys_can_pay_out() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let teammate = Address::generate(&env);
+        let winner1 = Address::generate(&env);
+        let winner2 = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
+
+        let keys = client.add_payout_key(&prog_id, &backend, &teammate);
+        assert_eq!(keys, soroban_sdk::vec![&env, backend.clone(), teammate.clone()]);
+
+        client.single_payout(&prog_id, &backend, &winner1, &1_000_0000000);
+        let recipients = soroban_sdk::vec![&env, winner2.clone()];
+        let amounts = soroban_sdk::vec![&env, 2_000_0000000i128];
+        let updated = client.batch_payout(&prog_id, &teammate, &recipients, &amounts);
+
+        assert_eq!(updated.remaining_balance, 7_000_0000000);
+        assert_eq!(token_client.balance(&winner1), 1_000_0000000);
+        assert_eq!(token_client.balance(&winner2), 2_000_0000000);
+
+        // Once removed, the teammate can no longer pay out
+        client.remove_payout_key(&prog_id, &backend, &teammate);
+        assert_eq!(
+            client.get_payout_keys(&prog_id),
+            soroban_sdk::vec![&env, backend.clone()]
+        );
+        let result = client.try_single_payout(&prog_id, &teammate, &winner1, &1_000_0000000);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized: caller is not a payout key")]
+    fn test_single_payout_rejects_unknown_key() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        let organizer = create_funded_account(&env, &token_client.address, &10_000_0000000);
+        client.lock_program_funds(&prog_id, &organizer, &10_000_0000000);
+
+        let stranger = Address::generate(&env);
+        client.single_payout(&prog_id, &stranger, &Address::generate(&env), &1_000_0000000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot remove the authorized payout key")]
+    fn test_cannot_remove_authorized_payout_key() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let teammate = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token);
+        client.add_payout_key(&prog_id, &backend, &teammate);
+        client.remove_payout_key(&prog_id, &teammate, &backend);
     }
 
     #[test]
@@ -1,7 +1,7 @@
 
 #![cfg(test)]
 extern crate std;
-use crate::{ProgramEscrowContract, ProgramEscrowContractClient, ProgramFilter, PayoutFilter, Pagination, ProgramData};
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient, ProgramFilter, PayoutFilter, Pagination};
 use soroban_sdk::{testutils::{Address as _, Ledger}, token, Address, Env, String};
 
 fn create_token_contract<'a>(
@@ -85,8 +85,8 @@ fn test_get_payouts_filtering() {
     
     // Lock funds
     let amount = 1000i128;
-    client.lock_program_funds(&p1, &amount);
-    token_admin.mint(&client.address, &amount); // Mock funding the contract for payouts
+    token_admin.mint(&backend, &amount);
+    client.lock_program_funds(&p1, &backend, &amount);
     // Actually lock_program_funds converts caller funds.
     // We should mint to caller first.
     // But since we mock auths, we can just assume funds are there if we mint to the source?
@@ -178,7 +178,7 @@ fn test_get_payouts_filtering() {
     // If it pulls from `env.caller()`, then in test `client.lock_program_funds` usually uses a default caller or we verify who it calls.
     
     // Let's try `lock_program_funds`. If it fails, I'll debug.
-    client.lock_program_funds(&p1, &amount_locked);
+    client.lock_program_funds(&p1, &backend, &amount_locked);
     
     // 3. Perform Payouts
     let recipient1 = Address::generate(&env);
@@ -186,17 +186,17 @@ fn test_get_payouts_filtering() {
     
     let now = env.ledger().timestamp();
     
-    client.single_payout(&p1, &recipient1, &100);
+    client.single_payout(&p1, &backend, &recipient1, &100);
     // Advance time
     env.ledger().set_timestamp(now + 100);
-    client.single_payout(&p1, &recipient2, &200);
+    client.single_payout(&p1, &backend, &recipient2, &200);
     
     // Query Payouts
     let filter_r1 = PayoutFilter {
         recipient: Some(recipient1.clone()),
         min_amount: None, max_amount: None, start_time: None, end_time: None
     };
-    let payouts_r1 = client.get_payouts(&p1, &filter_r1);
+    let payouts_r1 = client.get_payouts(&p1, &filter_r1, &Pagination { start_index: 0, limit: 10 });
     assert_eq!(payouts_r1.len(), 1);
     assert_eq!(payouts_r1.get(0).unwrap().amount, 100);
     
@@ -205,7 +205,98 @@ fn test_get_payouts_filtering() {
         min_amount: Some(150),
         max_amount: None, start_time: None, end_time: None
     };
-    let payouts_amt = client.get_payouts(&p1, &filter_amt);
+    let payouts_amt = client.get_payouts(&p1, &filter_amt, &Pagination { start_index: 0, limit: 10 });
     assert_eq!(payouts_amt.len(), 1);
     assert_eq!(payouts_amt.get(0).unwrap().recipient, recipient2);
 }
+
+#[test]
+fn test_get_payouts_pagination() {
+    let env = Env::default();
+    let (client, _admin, token, _token_client, token_admin) = create_test_env(&env);
+    
+    let backend = Address::generate(&env);
+    let p1 = String::from_str(&env, "P1");
+    client.initialize_program(&p1, &backend, &token);
+    token_admin.mint(&backend, &1000);
+    client.lock_program_funds(&p1, &backend, &1000);
+    
+    // Ten payouts with amounts 1..=10, recorded in that order
+    let mut recipients = soroban_sdk::vec![&env];
+    let mut amounts = soroban_sdk::vec![&env];
+    for amount in 1..=10i128 {
+        recipients.push_back(Address::generate(&env));
+        amounts.push_back(amount);
+    }
+    client.batch_payout(&p1, &backend, &recipients, &amounts);
+    
+    let no_filter = PayoutFilter {
+        recipient: None,
+        min_amount: None, max_amount: None, start_time: None, end_time: None
+    };
+    let page2 = client.get_payouts(&p1, &no_filter, &Pagination { start_index: 3, limit: 3 });
+    assert_eq!(page2.len(), 3);
+    assert_eq!(page2.get(0).unwrap().amount, 4);
+    assert_eq!(page2.get(1).unwrap().amount, 5);
+    assert_eq!(page2.get(2).unwrap().amount, 6);
+    assert_eq!(page2.get(0).unwrap().recipient, recipients.get(3).unwrap());
+    
+    // The filter is applied before the page window
+    let filter_min = PayoutFilter {
+        recipient: None,
+        min_amount: Some(5),
+        max_amount: None, start_time: None, end_time: None
+    };
+    let page2 = client.get_payouts(&p1, &filter_min, &Pagination { start_index: 3, limit: 3 });
+    assert_eq!(page2.len(), 3);
+    assert_eq!(page2.get(0).unwrap().amount, 8);
+    assert_eq!(page2.get(2).unwrap().amount, 10);
+    
+    // Past the end
+    let page = client.get_payouts(&p1, &filter_min, &Pagination { start_index: 6, limit: 3 });
+    assert_eq!(page.len(), 0);
+}
+
+#[test]
+fn test_get_payout_by_index() {
+    let env = Env::default();
+    let (client, _admin, token, _token_client, token_admin) = create_test_env(&env);
+
+    let backend = Address::generate(&env);
+    let p1 = String::from_str(&env, "P1");
+    client.initialize_program(&p1, &backend, &token);
+    token_admin.mint(&backend, &1000);
+    client.lock_program_funds(&p1, &backend, &1000);
+    assert_eq!(client.get_payout_count(&p1), 0);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.batch_payout(
+        &p1,
+        &backend,
+        &soroban_sdk::vec![&env, first.clone(), second.clone()],
+        &soroban_sdk::vec![&env, 100i128, 200i128],
+    );
+
+    assert_eq!(client.get_payout_count(&p1), 2);
+    let payout = client.get_payout(&p1, &1);
+    assert_eq!(payout.recipient, second);
+    assert_eq!(payout.amount, 200);
+    assert_eq!(client.get_payout(&p1, &0).recipient, first);
+}
+
+#[test]
+#[should_panic(expected = "Payout index out of range")]
+fn test_get_payout_out_of_range() {
+    let env = Env::default();
+    let (client, _admin, token, _token_client, token_admin) = create_test_env(&env);
+
+    let backend = Address::generate(&env);
+    let p1 = String::from_str(&env, "P1");
+    client.initialize_program(&p1, &backend, &token);
+    token_admin.mint(&backend, &1000);
+    client.lock_program_funds(&p1, &backend, &1000);
+    client.single_payout(&p1, &backend, &Address::generate(&env), &100);
+
+    client.get_payout(&p1, &1);
+}